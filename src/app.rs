@@ -2,6 +2,7 @@ use std::borrow::Cow;
 use std::io::Read;
 use std::io::Write;
 use std::ops::DerefMut;
+use std::path::Path;
 use std::process::Child;
 use std::process::Command;
 use std::process::Stdio;
@@ -31,6 +32,7 @@ use crate::file_watcher::FileWatcher;
 use crate::item_data::Rev;
 use crate::menu::Menu;
 use crate::menu::PendingMenu;
+use crate::ops;
 use crate::ops::Op;
 use crate::picker::PickerData;
 use crate::picker::PickerState;
@@ -81,6 +83,18 @@ impl App {
                     reference.clone(),
                 )?]
             }
+            Some(cli::Commands::Blame {
+                ref path,
+                ref reference,
+            }) => {
+                vec![screen::blame::create(
+                    Arc::clone(&config),
+                    Rc::clone(&repo),
+                    size,
+                    workdir_relative_path(&repo, path),
+                    reference.clone(),
+                )?]
+            }
             None => vec![screen::status::create(
                 Arc::clone(&config),
                 Rc::clone(&repo),
@@ -191,6 +205,24 @@ impl App {
         Ok(())
     }
 
+    /// Reopens the app on another repository (e.g. a linked worktree),
+    /// replacing all screens with a fresh status screen.
+    pub(crate) fn reopen(&mut self, repo: Repository) -> Res<()> {
+        let size = self.screen().size;
+        let repo = Rc::new(repo);
+
+        self.state.screens = vec![screen::status::create(
+            Arc::clone(&self.state.config),
+            Rc::clone(&repo),
+            size,
+        )?];
+        self.state.repo = repo;
+        self.state.file_watcher = self.init_file_watcher()?;
+
+        self.stage_redraw();
+        Ok(())
+    }
+
     pub fn handle_event(&mut self, term: &mut Term, event: Event) -> Res<()> {
         match event {
             Event::Resize(w, h) => {
@@ -265,13 +297,19 @@ impl App {
 
         self.state.pending_keys.push((key.modifiers, key.code));
 
-        let matching_bindings = self
-            .state
-            .config
+        let config = Arc::clone(&self.state.config);
+        let mut matching_bindings = config
             .bindings
             .match_bindings(&menu, &self.state.pending_keys)
             .collect::<Vec<_>>();
 
+        if matching_bindings.is_empty() && self.screen().menu == Some(menu) {
+            matching_bindings = config
+                .bindings
+                .match_bindings(&Menu::Root, &self.state.pending_keys)
+                .collect();
+        }
+
         match matching_bindings[..] {
             [binding] => {
                 if binding.keys == self.state.pending_keys {
@@ -335,9 +373,25 @@ impl App {
         let screen_ref = self.screen();
         let item_data = &screen_ref.get_selected_item().data;
         let implementation = op.clone().implementation();
+        let marked_action = match ops::marked_action(implementation.as_ref(), screen_ref) {
+            Ok(marked_action) => marked_action,
+            Err(error) => {
+                self.handle_result(Err::<(), _>(error))?;
+                self.close_menu();
+                return Ok(());
+            }
+        };
+        let acts_on_marks = marked_action.is_some();
+        let screen_i = self.state.screens.len() - 1;
 
-        if let Some(mut action) = implementation.get_action(item_data) {
+        if let Some(mut action) = marked_action.or_else(|| implementation.get_action(item_data)) {
             let result = Rc::get_mut(&mut action).unwrap()(self, term);
+            if acts_on_marks
+                && result.is_ok()
+                && let Some(screen) = self.state.screens.get_mut(screen_i)
+            {
+                screen.clear_marks();
+            }
             self.handle_result(result)?;
             if !self.state.inhibit_close_menu {
                 self.close_menu();
@@ -364,7 +418,13 @@ impl App {
     }
 
     pub fn close_menu(&mut self) {
-        self.state.pending_menu = root_menu(&self.state.config).map(PendingMenu::init)
+        self.state.pending_menu = self
+            .state
+            .screens
+            .last()
+            .and_then(|screen| screen.menu)
+            .or_else(|| root_menu(&self.state.config))
+            .map(PendingMenu::init)
     }
 
     pub fn inhibit_close_menu(&mut self) {
@@ -745,6 +805,20 @@ fn tee(maybe_input: Option<&mut impl Read>, outputs: &mut [&mut dyn Write]) -> s
     Ok(())
 }
 
+/// Paths given on the command line are relative to the current directory,
+/// falls back to treating them as relative to the workdir if they don't exist.
+fn workdir_relative_path(repo: &Repository, path: &Path) -> String {
+    let workdir = repo.workdir().expect("Bare repos unhandled");
+
+    path.canonicalize()
+        .ok()
+        .zip(workdir.canonicalize().ok())
+        .and_then(|(path, workdir)| path.strip_prefix(workdir).ok().map(Path::to_path_buf))
+        .unwrap_or_else(|| path.to_path_buf())
+        .to_string_lossy()
+        .into_owned()
+}
+
 pub(crate) fn root_menu(config: &Config) -> Option<Menu> {
     if config.general.always_show_help.enabled {
         Some(Menu::Help)
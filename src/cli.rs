@@ -36,5 +36,12 @@ pub struct Args {
 
 #[derive(Debug, Subcommand)]
 pub enum Commands {
-    Show { reference: String },
+    Show {
+        reference: String,
+    },
+    /// Blame a file, optionally at a given revision
+    Blame {
+        path: PathBuf,
+        reference: Option<String>,
+    },
 }
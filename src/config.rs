@@ -107,11 +107,18 @@ pub struct StyleConfig {
     pub selection_bar: SymbolStyleConfigEntry,
     pub selection_line: StyleConfigEntry,
     pub selection_area: StyleConfigEntry,
+    /// Items marked to be acted on together
+    #[serde(default)]
+    pub marked: SymbolStyleConfigEntry,
 
     pub hash: StyleConfigEntry,
     pub branch: StyleConfigEntry,
     pub remote: StyleConfigEntry,
     pub tag: StyleConfigEntry,
+    pub rebase_action: StyleConfigEntry,
+
+    #[serde(default)]
+    pub graph: GraphStyleConfig,
 }
 
 #[derive(Default, Debug, Deserialize)]
@@ -207,6 +214,19 @@ pub struct PickerStyleConfig {
     pub matched: StyleConfigEntry,
 }
 
+#[derive(Default, Debug, Deserialize)]
+pub struct GraphStyleConfig {
+    /// Draw lanes with ASCII characters rather than box-drawing ones
+    #[serde(default)]
+    pub ascii: bool,
+    /// Applied on top of the lane style
+    #[serde(default)]
+    pub commit: StyleConfigEntry,
+    /// Styles cycled through from left to right
+    #[serde(default)]
+    pub lanes: Vec<StyleConfigEntry>,
+}
+
 #[derive(Default, Debug, Deserialize)]
 pub struct StyleConfigEntry {
     #[serde(default)]
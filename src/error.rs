@@ -4,6 +4,7 @@ use std::{fmt::Display, io, string};
 pub enum Error {
     StashList(git2::Error),
     ReadLog(git2::Error),
+    ReadReflog(git2::Error),
     OpenRepo(git2::Error),
     FindGitDir(io::Error),
     Term(io::Error),
@@ -27,6 +28,7 @@ pub enum Error {
     RemoteNameUtf8(Utf8Error),
     CannotDeleteCurrentBranch,
     BranchNameRequired,
+    TagNameRequired,
     IsBranchMerged(git2::Error),
     GetRemote(git2::Error),
     ReadGitConfig(git2::Error),
@@ -58,6 +60,47 @@ pub enum Error {
     GetBranchName(git2::Error),
     BaseCommitOid,
     UpstreamCommitOid,
+    ListWorktrees(io::Error),
+    WorktreePathRequired,
+    NoOtherWorktrees,
+    ListSubmodules(git2::Error),
+    OpenSubmodule(git2::Error),
+    SubmoduleNotCheckedOut,
+    ReadBisectStatus(io::Error),
+    BisectScriptRequired,
+    ReadRebaseTodo(io::Error),
+    WriteRebaseTodo(io::Error),
+    ExecCommandRequired,
+    GitBlame(io::Error),
+    GitBlameFailed(String),
+    NoBlameParent,
+    DiffPathRequired,
+    GitLog(io::Error),
+    GitLogFailed(String),
+    ReadConflictFile(io::Error),
+    WriteConflictFile(io::Error),
+    ConflictRegionChanged,
+    MarkedItemsUnsupported,
+    WriteEditedPatch(io::Error),
+    ReadEditedPatch(io::Error),
+    GitApply(io::Error),
+    EditedPatchInvalid(String),
+    ReadAmStatusFile(io::Error),
+    PatchPathRequired,
+    ReadPatchDir(io::Error),
+    GitClean(io::Error),
+    GitCleanFailed(String),
+    NoExcludesFile,
+    ReadIgnoreFile(io::Error),
+    WriteIgnoreFile(io::Error),
+    RebaseStoppedOnConflicts,
+    CommitNotOnBranch,
+    NoCommitToSwapWith,
+    MoveCommitAcrossMerge,
+    MoveCommitPushed,
+    SplitRootCommit,
+    ReadSplitStatusFile(io::Error),
+    WriteSplitStatusFile(io::Error),
 }
 
 impl std::error::Error for Error {}
@@ -67,6 +110,7 @@ impl Display for Error {
         match self {
             Error::StashList(e) => f.write_fmt(format_args!("Couldn't list stash: {e}")),
             Error::ReadLog(e) => f.write_fmt(format_args!("Couldn't read log: {e}")),
+            Error::ReadReflog(e) => f.write_fmt(format_args!("Couldn't read reflog: {e}")),
             Error::OpenRepo(e) => match e.code() {
                 git2::ErrorCode::NotFound => f.write_str("No .git found in the current directory"),
                 _ => f.write_fmt(format_args!("Couldn't open repo: {e:?}")),
@@ -111,6 +155,7 @@ impl Display for Error {
             Error::RemoteNameUtf8(_e) => f.write_str("Remote name is not valid UTF-8"),
             Error::CannotDeleteCurrentBranch => f.write_str("Cannot delete current branch"),
             Error::BranchNameRequired => f.write_str("Branch name required"),
+            Error::TagNameRequired => f.write_str("Tag name required"),
             Error::IsBranchMerged(e) => {
                 f.write_fmt(format_args!("Couldn't check if branch is merged: {e}"))
             }
@@ -164,6 +209,83 @@ impl Display for Error {
             Error::UpstreamCommitOid => {
                 f.write_str("Could not resolve OID of upstream branch commit")
             }
+            Error::ListWorktrees(e) => f.write_fmt(format_args!("Couldn't list worktrees: {e}")),
+            Error::WorktreePathRequired => f.write_str("Worktree path required"),
+            Error::NoOtherWorktrees => f.write_str("No other worktrees"),
+            Error::ListSubmodules(e) => f.write_fmt(format_args!("Couldn't list submodules: {e}")),
+            Error::OpenSubmodule(e) => f.write_fmt(format_args!("Couldn't open submodule: {e}")),
+            Error::SubmoduleNotCheckedOut => f.write_str("Submodule is not checked out"),
+            Error::ReadBisectStatus(e) => {
+                f.write_fmt(format_args!("Couldn't read bisect status: {e}"))
+            }
+            Error::BisectScriptRequired => f.write_str("Bisect run script is required"),
+            Error::ReadRebaseTodo(e) => f.write_fmt(format_args!("Couldn't read rebase todo: {e}")),
+            Error::WriteRebaseTodo(e) => {
+                f.write_fmt(format_args!("Couldn't write rebase todo: {e}"))
+            }
+            Error::ExecCommandRequired => f.write_str("Exec command is required"),
+            Error::GitBlame(e) => f.write_fmt(format_args!("Git blame error: {e}")),
+            Error::GitBlameFailed(e) => f.write_fmt(format_args!("Couldn't blame: {e}")),
+            Error::NoBlameParent => f.write_str("No parent commit to blame"),
+            Error::DiffPathRequired => f.write_str("Diff path is required"),
+            Error::GitLog(e) => f.write_fmt(format_args!("Git log error: {e}")),
+            Error::GitLogFailed(e) => f.write_fmt(format_args!("Couldn't filter log: {e}")),
+            Error::ReadConflictFile(e) => {
+                f.write_fmt(format_args!("Couldn't read conflicted file: {e}"))
+            }
+            Error::WriteConflictFile(e) => {
+                f.write_fmt(format_args!("Couldn't write conflicted file: {e}"))
+            }
+            Error::MarkedItemsUnsupported => {
+                f.write_str("Can't act on all of the marked items, clear the marks to act on one")
+            }
+            Error::ConflictRegionChanged => {
+                f.write_str("The conflict changed since it was shown, try again")
+            }
+            Error::WriteEditedPatch(e) => {
+                f.write_fmt(format_args!("Couldn't write patch for editing: {e}"))
+            }
+            Error::ReadEditedPatch(e) => {
+                f.write_fmt(format_args!("Couldn't read edited patch: {e}"))
+            }
+            Error::GitApply(e) => f.write_fmt(format_args!("Couldn't run git apply: {e}")),
+            Error::EditedPatchInvalid(e) => f.write_fmt(format_args!(
+                "Edited patch doesn't apply, nothing staged: {e}"
+            )),
+            Error::ReadAmStatusFile(e) => {
+                f.write_fmt(format_args!("Couldn't read git am status: {e}"))
+            }
+            Error::PatchPathRequired => f.write_str("Patch file or directory is required"),
+            Error::ReadPatchDir(e) => {
+                f.write_fmt(format_args!("Couldn't read patch directory: {e}"))
+            }
+            Error::GitClean(e) => f.write_fmt(format_args!("Couldn't run git clean: {e}")),
+            Error::GitCleanFailed(e) => f.write_fmt(format_args!("git clean failed: {e}")),
+            Error::NoExcludesFile => {
+                f.write_str("Couldn't locate the global ignore file, set core.excludesFile")
+            }
+            Error::ReadIgnoreFile(e) => f.write_fmt(format_args!("Couldn't read ignore file: {e}")),
+            Error::WriteIgnoreFile(e) => {
+                f.write_fmt(format_args!("Couldn't write ignore file: {e}"))
+            }
+            Error::RebaseStoppedOnConflicts => f.write_str(
+                "Rebase stopped on conflicts. Resolve them, then continue or abort the rebase",
+            ),
+            Error::CommitNotOnBranch => f.write_str("Commit is not on the current branch"),
+            Error::NoCommitToSwapWith => f.write_str("No commit to swap with"),
+            Error::MoveCommitAcrossMerge => {
+                f.write_str("Can't rewrite commits when the range contains merge commits")
+            }
+            Error::MoveCommitPushed => {
+                f.write_str("Can't move commits that are already pushed to the upstream")
+            }
+            Error::SplitRootCommit => f.write_str("Can't split the root commit"),
+            Error::ReadSplitStatusFile(e) => {
+                f.write_fmt(format_args!("Couldn't read split status file: {e}"))
+            }
+            Error::WriteSplitStatusFile(e) => {
+                f.write_fmt(format_args!("Couldn't write split status file: {e}"))
+            }
         }
     }
 }
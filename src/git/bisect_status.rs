@@ -0,0 +1,10 @@
+#[derive(Debug, Clone)]
+pub(crate) enum BisectStatus {
+    /// Waiting for both a good and a bad commit to be marked
+    Started,
+    Bisecting {
+        revisions_left: u32,
+        steps: u32,
+    },
+    FoundFirstBad(String),
+}
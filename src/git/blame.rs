@@ -0,0 +1,207 @@
+use crate::{Res, error::Error, highlight, items::hash};
+use git2::Repository;
+use std::{ops::Range, process::Command};
+
+#[derive(Debug, Clone)]
+pub(crate) struct Blame {
+    pub id: u64,
+    pub path: String,
+    /// File content, each line terminated by a newline
+    pub text: String,
+    pub lines: Vec<BlameLine>,
+    pub chunks: Vec<BlameChunk>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct BlameLine {
+    pub range: Range<usize>,
+    /// Line number (1-based) in the version of the file the commit introduced
+    pub orig_line: usize,
+}
+
+/// Consecutive lines last touched by the same commit.
+#[derive(Debug, Clone)]
+pub(crate) struct BlameChunk {
+    pub oid: String,
+    pub author: String,
+    pub date: String,
+    pub summary: String,
+    /// The parent commit and the path the file had there
+    pub previous: Option<(String, String)>,
+    pub lines: Range<usize>,
+}
+
+impl BlameChunk {
+    /// Lines not committed yet are attributed to an all-zero hash.
+    pub(crate) fn is_committed(&self) -> bool {
+        self.oid.chars().any(|c| c != '0')
+    }
+}
+
+pub(crate) fn blame(repo: &Repository, path: &str, rev: Option<&str>) -> Res<Blame> {
+    let output = Command::new("git")
+        .current_dir(repo.workdir().expect("Bare repos unhandled"))
+        .args(["blame", "--line-porcelain"])
+        .args(rev)
+        .args(["--", path])
+        .output()
+        .map_err(Error::GitBlame)?;
+
+    if !output.status.success() {
+        return Err(Error::GitBlameFailed(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+
+    Ok(parse(path, rev, &String::from_utf8_lossy(&output.stdout)))
+}
+
+fn parse(path: &str, rev: Option<&str>, porcelain: &str) -> Blame {
+    let mut text = String::new();
+    let mut orig_lines = vec![];
+    let mut chunks: Vec<BlameChunk> = vec![];
+
+    let mut current = None;
+    let mut author = "";
+    let mut time = 0;
+    let mut tz = "+0000";
+    let mut summary = "";
+    let mut previous = None;
+
+    for line in porcelain.lines() {
+        if let Some(content) = line.strip_prefix('\t') {
+            let Some((oid, orig_line)) = current.take() else {
+                continue;
+            };
+
+            let line_i = orig_lines.len();
+            text.push_str(content);
+            text.push('\n');
+            orig_lines.push(orig_line);
+
+            match chunks.last_mut() {
+                Some(chunk) if chunk.oid == oid => chunk.lines.end = line_i + 1,
+                _ => chunks.push(BlameChunk {
+                    oid,
+                    author: author.to_string(),
+                    date: format_date(time, tz),
+                    summary: summary.to_string(),
+                    previous: previous.take(),
+                    lines: line_i..line_i + 1,
+                }),
+            }
+
+            previous = None;
+            continue;
+        }
+
+        let (key, value) = line.split_once(' ').unwrap_or((line, ""));
+        match key {
+            "author" => author = value,
+            "author-time" => time = value.parse().unwrap_or(0),
+            "author-tz" => tz = value,
+            "summary" => summary = value,
+            "previous" => {
+                previous = value
+                    .split_once(' ')
+                    .map(|(oid, path)| (oid.to_string(), path.to_string()))
+            }
+            _ if key.len() == 40 && key.chars().all(|c| c.is_ascii_hexdigit()) => {
+                let orig_line = value
+                    .split(' ')
+                    .next()
+                    .and_then(|n| n.parse().ok())
+                    .unwrap_or(1);
+
+                current = Some((key.to_string(), orig_line));
+            }
+            _ => (),
+        }
+    }
+
+    let lines = highlight::line_range_iterator(&text)
+        .zip(orig_lines)
+        .map(|((range, _), orig_line)| BlameLine { range, orig_line })
+        .collect();
+
+    Blame {
+        id: hash((path, rev, &text)),
+        path: path.to_string(),
+        text,
+        lines,
+        chunks,
+    }
+}
+
+fn format_date(time: i64, tz: &str) -> String {
+    let sign = if tz.starts_with('-') { -1 } else { 1 };
+    let digits = tz.trim_start_matches(['+', '-']);
+    let hours: i32 = digits.get(..2).and_then(|h| h.parse().ok()).unwrap_or(0);
+    let minutes: i32 = digits.get(2..).and_then(|m| m.parse().ok()).unwrap_or(0);
+
+    let offset = chrono::FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+        .unwrap_or(chrono::FixedOffset::east_opt(0).unwrap());
+
+    chrono::DateTime::from_timestamp(time, 0)
+        .unwrap_or_default()
+        .with_timezone(&offset)
+        .format("%Y-%m-%d")
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const A: &str = "1111111111111111111111111111111111111111";
+    const B: &str = "2222222222222222222222222222222222222222";
+
+    fn record(oid: &str, orig: usize, fin: usize, content: &str, previous: bool) -> String {
+        let previous = if previous {
+            format!("previous {A} old.rs\n")
+        } else {
+            String::new()
+        };
+
+        format!(
+            "{oid} {orig} {fin}\nauthor Author {oid:.1}\nauthor-mail <a@b.c>\n\
+             author-time 86400\nauthor-tz -0100\nsummary Commit {oid:.1}\n\
+             {previous}filename file.rs\n\t{content}\n"
+        )
+    }
+
+    #[test]
+    fn parse_blame() {
+        let porcelain = [
+            record(A, 1, 1, "fn main() {", false),
+            record(A, 2, 2, "    let x = 1;", false),
+            record(B, 2, 3, "    let y = 2;", true),
+            record(A, 3, 4, "}", false),
+        ]
+        .concat();
+
+        let blame = parse("file.rs", None, &porcelain);
+
+        assert_eq!(
+            blame.text,
+            "fn main() {\n    let x = 1;\n    let y = 2;\n}\n"
+        );
+        assert_eq!(
+            blame
+                .chunks
+                .iter()
+                .map(|chunk| (&chunk.oid[..1], chunk.lines.clone()))
+                .collect::<Vec<_>>(),
+            vec![("1", 0..2), ("2", 2..3), ("1", 3..4)]
+        );
+
+        let chunk = &blame.chunks[1];
+        assert_eq!(chunk.author, "Author 2");
+        assert_eq!(chunk.summary, "Commit 2");
+        assert_eq!(chunk.date, "1970-01-01");
+        assert_eq!(chunk.previous, Some((A.to_string(), "old.rs".to_string())));
+        assert_eq!(blame.chunks[2].previous, None);
+        assert_eq!(blame.lines[2].orig_line, 2);
+        assert_eq!(&blame.text[blame.lines[3].range.clone()], "}");
+    }
+}
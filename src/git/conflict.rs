@@ -0,0 +1,231 @@
+use crate::{Res, error::Error, highlight};
+use std::{fs, ops::Range, path::Path};
+
+/// A file left with conflict markers by a merge, rebase, cherry-pick or revert.
+#[derive(Debug, Clone)]
+pub(crate) struct ConflictFile {
+    pub path: String,
+    pub text: String,
+    pub regions: Vec<ConflictRegion>,
+}
+
+/// A `<<<<<<<` to `>>>>>>>` block. Ranges index into the file's text and span whole lines.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ConflictRegion {
+    pub range: Range<usize>,
+    /// Line number (1-based) of the `<<<<<<<` marker
+    pub line: usize,
+    pub ours: Range<usize>,
+    /// Only present with `merge.conflictStyle` set to `diff3` or `zdiff3`
+    pub base: Option<Range<usize>>,
+    pub theirs: Range<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ConflictSide {
+    Marker,
+    Ours,
+    Base,
+    Theirs,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Resolution {
+    Ours,
+    Theirs,
+    Both,
+}
+
+impl ConflictFile {
+    /// The file's text with one region replaced by the chosen side(s).
+    pub(crate) fn resolve(&self, region_i: usize, resolution: Resolution) -> String {
+        let region = &self.regions[region_i];
+        let ours = &self.text[region.ours.clone()];
+        let theirs = &self.text[region.theirs.clone()];
+
+        let replacement = match resolution {
+            Resolution::Ours => ours.to_string(),
+            Resolution::Theirs => theirs.to_string(),
+            Resolution::Both => format!("{ours}{theirs}"),
+        };
+
+        [
+            &self.text[..region.range.start],
+            &replacement,
+            &self.text[region.range.end..],
+        ]
+        .concat()
+    }
+
+    /// The whole region, markers included.
+    pub(crate) fn region_text(&self, region_i: usize) -> Option<&str> {
+        self.regions
+            .get(region_i)
+            .map(|region| &self.text[region.range.clone()])
+    }
+
+    /// Each line of a region along with the part of the conflict it belongs to.
+    pub(crate) fn region_lines(&self, region_i: usize) -> Vec<(Range<usize>, ConflictSide)> {
+        let region = &self.regions[region_i];
+
+        highlight::line_range_iterator(&self.text[region.range.clone()])
+            .map(|(range, _)| {
+                let range = range.start + region.range.start..range.end + region.range.start;
+                let side = if region.ours.contains(&range.start) {
+                    ConflictSide::Ours
+                } else if region.theirs.contains(&range.start) {
+                    ConflictSide::Theirs
+                } else if region
+                    .base
+                    .as_ref()
+                    .is_some_and(|base| base.contains(&range.start))
+                {
+                    ConflictSide::Base
+                } else {
+                    ConflictSide::Marker
+                };
+
+                (range, side)
+            })
+            .collect()
+    }
+}
+
+pub(crate) fn read_conflicts(workdir: &Path, path: &str) -> Res<ConflictFile> {
+    let text = fs::read_to_string(workdir.join(path)).map_err(Error::ReadConflictFile)?;
+
+    Ok(ConflictFile {
+        path: path.to_string(),
+        regions: parse(&text),
+        text,
+    })
+}
+
+fn parse(text: &str) -> Vec<ConflictRegion> {
+    let mut regions = vec![];
+    let mut start = None;
+    let mut base_start = None;
+    let mut separator = None;
+    let mut offset = 0;
+
+    for (line_i, line) in text.split_inclusive('\n').enumerate() {
+        let line_start = offset;
+        offset += line.len();
+
+        if is_marker(line, "<<<<<<<") {
+            start = Some((line_start, line_i + 1, offset));
+            base_start = None;
+            separator = None;
+        } else if start.is_some() && separator.is_none() && is_marker(line, "|||||||") {
+            base_start = Some((line_start, offset));
+        } else if start.is_some() && separator.is_none() && is_marker(line, "=======") {
+            separator = Some((line_start, offset));
+        } else if is_marker(line, ">>>>>>>")
+            && let (Some((region_start, line, ours_start)), Some((separator_start, theirs_start))) =
+                (start.take(), separator.take())
+        {
+            let ours_end = base_start.map(|(base, _)| base).unwrap_or(separator_start);
+
+            regions.push(ConflictRegion {
+                range: region_start..offset,
+                line,
+                ours: ours_start..ours_end,
+                base: base_start
+                    .take()
+                    .map(|(_, base_start)| base_start..separator_start),
+                theirs: theirs_start..line_start,
+            });
+        }
+    }
+
+    regions
+}
+
+fn is_marker(line: &str, marker: &str) -> bool {
+    line.strip_prefix(marker)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with([' ', '\n', '\r']))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "\
+fn main() {
+<<<<<<< HEAD
+    ours();
+=======
+    theirs();
+>>>>>>> other-branch
+}
+<<<<<<< HEAD
+a
+||||||| base
+b
+=======
+c
+>>>>>>> other-branch
+";
+
+    fn file() -> ConflictFile {
+        ConflictFile {
+            path: "file.rs".into(),
+            text: TEXT.into(),
+            regions: parse(TEXT),
+        }
+    }
+
+    #[test]
+    fn parse_regions() {
+        let file = file();
+        assert_eq!(file.regions.len(), 2);
+
+        let first = &file.regions[0];
+        assert_eq!(first.line, 2);
+        assert_eq!(&TEXT[first.ours.clone()], "    ours();\n");
+        assert_eq!(&TEXT[first.theirs.clone()], "    theirs();\n");
+        assert_eq!(first.base, None);
+
+        let second = &file.regions[1];
+        assert_eq!(second.line, 8);
+        assert_eq!(&TEXT[second.ours.clone()], "a\n");
+        assert_eq!(second.base.clone().map(|base| &TEXT[base]), Some("b\n"));
+        assert_eq!(&TEXT[second.theirs.clone()], "c\n");
+    }
+
+    #[test]
+    fn resolve_regions() {
+        let file = file();
+        assert!(
+            file.resolve(0, Resolution::Ours)
+                .starts_with("fn main() {\n    ours();\n}\n<<<<<<<")
+        );
+        assert!(
+            file.resolve(0, Resolution::Both)
+                .starts_with("fn main() {\n    ours();\n    theirs();\n}\n")
+        );
+        assert!(file.resolve(1, Resolution::Theirs).ends_with("}\nc\n"));
+    }
+
+    #[test]
+    fn region_lines() {
+        let sides = file()
+            .region_lines(1)
+            .into_iter()
+            .map(|(_, side)| side)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            sides,
+            vec![
+                ConflictSide::Marker,
+                ConflictSide::Ours,
+                ConflictSide::Marker,
+                ConflictSide::Base,
+                ConflictSide::Marker,
+                ConflictSide::Theirs,
+                ConflictSide::Marker,
+            ]
+        );
+    }
+}
@@ -1,10 +1,11 @@
 use crate::gitu_diff::FileDiff;
-use std::ops::Range;
 
 #[derive(Debug, Clone)]
 pub(crate) struct Diff {
     pub text: String,
     pub diff_type: DiffType,
+    /// The revision on the new side of the diff, `None` for the index or worktree
+    pub new_rev: Option<String>,
     pub file_diffs: Vec<FileDiff>,
 }
 
@@ -13,6 +14,53 @@ pub(crate) enum DiffType {
     WorkdirToIndex, // i.e. Unstaged
     IndexToTree,    // i.e. Staged
     TreeToTree,
+    /// `git diff <from>..<to>`
+    Range {
+        from: String,
+        to: String,
+    },
+    /// `git diff <from>...<to>`, changes on `to` since it forked off `from`
+    MergeBase {
+        from: String,
+        to: String,
+    },
+    /// `git diff <rev>`
+    WorkdirToCommit(String),
+    /// `git diff --cached <rev>`
+    IndexToCommit(String),
+    /// `git diff <from>..<to> -- <path>`
+    Path {
+        from: String,
+        to: String,
+        path: String,
+    },
+}
+
+impl DiffType {
+    pub(crate) fn args(&self) -> Vec<String> {
+        match self {
+            DiffType::WorkdirToIndex => vec![],
+            DiffType::IndexToTree => vec!["--cached".into()],
+            DiffType::TreeToTree => vec![],
+            DiffType::Range { from, to } => vec![format!("{from}..{to}")],
+            DiffType::MergeBase { from, to } => vec![format!("{from}...{to}")],
+            DiffType::WorkdirToCommit(rev) => vec![rev.clone()],
+            DiffType::IndexToCommit(rev) => vec!["--cached".into(), rev.clone()],
+            DiffType::Path { from, to, path } => {
+                vec![format!("{from}..{to}"), "--".into(), path.clone()]
+            }
+        }
+    }
+
+    /// The revision on the new side of the diff, if it isn't the index or worktree.
+    pub(crate) fn new_rev(&self) -> Option<String> {
+        match self {
+            DiffType::Range { to, .. }
+            | DiffType::MergeBase { to, .. }
+            | DiffType::Path { to, .. } => Some(to.clone()),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -67,7 +115,7 @@ impl Diff {
         &self,
         file_i: usize,
         hunk_i: usize,
-        line_range: Range<usize>,
+        line_is: &[usize],
         mode: PatchMode,
     ) -> String {
         let hunk = &self.file_diffs[file_i].hunks[hunk_i];
@@ -89,7 +137,7 @@ impl Diff {
                     PatchMode::Reverse => '+',
                 };
 
-                if line_range.contains(&i) {
+                if line_is.contains(&i) {
                     Some(line.to_string())
                 } else if line.starts_with(add) {
                     None
@@ -0,0 +1,50 @@
+use crate::{Res, error::Error};
+use git2::Repository;
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+/// The file `core.excludesFile` points to, or git's default of `$XDG_CONFIG_HOME/git/ignore`.
+pub(crate) fn excludes_file(repo: &Repository) -> Res<PathBuf> {
+    let config = repo.config().map_err(Error::ReadGitConfig)?;
+
+    match config.get_path("core.excludesFile") {
+        Ok(path) => Ok(path),
+        Err(e) if e.code() == git2::ErrorCode::NotFound => {
+            let config_home = match env::var_os("XDG_CONFIG_HOME").filter(|dir| !dir.is_empty()) {
+                Some(dir) => PathBuf::from(dir),
+                None => {
+                    PathBuf::from(env::var_os("HOME").ok_or(Error::NoExcludesFile)?).join(".config")
+                }
+            };
+
+            Ok(config_home.join("git").join("ignore"))
+        }
+        Err(e) => Err(Error::ReadGitConfig(e)),
+    }
+}
+
+/// Adds one pattern per line to the end of an ignore file, creating it if needed.
+pub(crate) fn append(file: &Path, patterns: &[String]) -> Res<()> {
+    let mut text = match fs::read_to_string(file) {
+        Ok(text) => text,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(Error::ReadIgnoreFile(e)),
+    };
+
+    if !text.is_empty() && !text.ends_with('\n') {
+        text.push('\n');
+    }
+
+    for pattern in patterns {
+        text.push_str(pattern);
+        text.push('\n');
+    }
+
+    if let Some(parent) = file.parent() {
+        fs::create_dir_all(parent).map_err(Error::WriteIgnoreFile)?;
+    }
+
+    fs::write(file, text).map_err(Error::WriteIgnoreFile)
+}
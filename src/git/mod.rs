@@ -1,9 +1,12 @@
 use diff::Diff;
-use git2::{Branch, Repository};
+use git2::{Branch, Oid, Repository};
 use itertools::Itertools;
 use remote::get_branch_upstream;
 
-use self::{commit::Commit, merge_status::MergeStatus, rebase_status::RebaseStatus};
+use self::{
+    bisect_status::BisectStatus, commit::Commit, merge_status::MergeStatus,
+    rebase_status::RebaseStatus, split_status::SplitStatus,
+};
 use crate::{
     Res,
     error::{Error, Utf8Error},
@@ -12,27 +15,34 @@ use crate::{
     item_data::{Ref, Rev},
 };
 use std::{
+    collections::HashSet,
     fs,
+    io::Write,
     path::Path,
-    process::Command,
+    process::{Command, Stdio},
     str::{self, FromStr},
 };
 
+pub(crate) mod bisect_status;
+pub(crate) mod blame;
 pub(crate) mod commit;
+pub(crate) mod conflict;
 pub(crate) mod diff;
+pub(crate) mod ignore;
 pub(crate) mod merge_status;
 mod parse;
 pub(crate) mod rebase_status;
+pub(crate) mod rebase_todo;
 pub(crate) mod remote;
+pub(crate) mod split_status;
 pub(crate) mod status;
+pub(crate) mod submodule;
+pub(crate) mod worktree;
 
 pub(crate) fn rebase_status(repo: &Repository) -> Res<Option<RebaseStatus>> {
     let dir = repo.workdir().expect("No workdir");
-    let mut rebase_onto_file = dir.to_path_buf();
-    rebase_onto_file.push(".git/rebase-merge/onto");
-
-    let mut rebase_head_name_file = dir.to_path_buf();
-    rebase_head_name_file.push(".git/rebase-merge/head-name");
+    let rebase_onto_file = repo.path().join("rebase-merge/onto");
+    let rebase_head_name_file = repo.path().join("rebase-merge/head-name");
 
     match fs::read_to_string(&rebase_onto_file) {
         Ok(content) => {
@@ -40,13 +50,16 @@ pub(crate) fn rebase_status(repo: &Repository) -> Res<Option<RebaseStatus>> {
             Ok(Some(RebaseStatus {
                 onto: branch_name_lossy(dir, &onto_hash)?
                     .unwrap_or_else(|| onto_hash[..7].to_string()),
+                onto_oid: onto_hash,
                 head_name: fs::read_to_string(rebase_head_name_file)
                     .map_err(Error::ReadRebaseStatusFile)?
                     .trim()
                     .strip_prefix("refs/heads/")
                     .unwrap()
                     .to_string(),
-                // TODO include log of 'done' items
+                done: rebase_todo::read_done(repo)?,
+                todo: rebase_todo::read(repo).unwrap_or_default(),
+                stopped: repo.path().join("rebase-merge/stopped-sha").exists(),
             }))
         }
         Err(err) => {
@@ -60,10 +73,55 @@ pub(crate) fn rebase_status(repo: &Repository) -> Res<Option<RebaseStatus>> {
     }
 }
 
+/// Records a commit split: the commit itself, and the base that new commits are created on.
+pub(crate) fn start_split(repo: &Repository, commit: &str, base: &str) -> Res<()> {
+    fs::write(split_file(repo), format!("{commit}\n{base}\n")).map_err(Error::WriteSplitStatusFile)
+}
+
+/// Forgets about the split, leaving the rebase itself as is.
+pub(crate) fn finish_split(repo: &Repository) -> Res<()> {
+    match fs::remove_file(split_file(repo)) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(Error::WriteSplitStatusFile(e)),
+        _ => Ok(()),
+    }
+}
+
+pub(crate) fn split_status(repo: &Repository) -> Res<Option<SplitStatus>> {
+    let content = match fs::read_to_string(split_file(repo)) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(Error::ReadSplitStatusFile(e)),
+    };
+
+    let mut lines = content.lines();
+    let commit = Oid::from_str(lines.next().unwrap_or_default()).map_err(Error::ReadOid)?;
+    let base = Oid::from_str(lines.next().unwrap_or_default()).map_err(Error::ReadOid)?;
+
+    let commit_tree = repo.find_commit(commit).map_err(Error::ReadLog)?.tree_id();
+    let head_tree = repo
+        .head()
+        .and_then(|head| head.peel_to_tree())
+        .map_err(Error::GetHead)?
+        .id();
+
+    let mut revwalk = repo.revwalk().map_err(Error::ReadLog)?;
+    revwalk.push_head().map_err(Error::ReadLog)?;
+    revwalk.hide(base).map_err(Error::ReadLog)?;
+
+    Ok(Some(SplitStatus {
+        commit: commit.to_string().chars().take(7).collect(),
+        created: revwalk.count(),
+        done: head_tree == commit_tree,
+    }))
+}
+
+fn split_file(repo: &Repository) -> std::path::PathBuf {
+    repo.path().join("rebase-merge/gitu-split")
+}
+
 pub(crate) fn merge_status(repo: &Repository) -> Res<Option<MergeStatus>> {
     let dir = repo.workdir().expect("No workdir");
-    let mut merge_head_file = dir.to_path_buf();
-    merge_head_file.push(".git/MERGE_HEAD");
+    let merge_head_file = repo.path().join("MERGE_HEAD");
 
     match fs::read_to_string(&merge_head_file) {
         Ok(content) => {
@@ -90,8 +148,7 @@ pub(crate) struct RevertStatus {
 
 pub(crate) fn revert_status(repo: &Repository) -> Res<Option<RevertStatus>> {
     let dir = repo.workdir().expect("No workdir");
-    let mut revert_head_file = dir.to_path_buf();
-    revert_head_file.push(".git/REVERT_HEAD");
+    let revert_head_file = repo.path().join("REVERT_HEAD");
 
     match fs::read_to_string(&revert_head_file) {
         Ok(content) => {
@@ -111,6 +168,34 @@ pub(crate) fn revert_status(repo: &Repository) -> Res<Option<RevertStatus>> {
     }
 }
 
+#[derive(Debug, Clone)]
+pub(crate) struct AmStatus {
+    /// 1-based number of the patch being applied
+    pub current: usize,
+    pub total: usize,
+}
+
+pub(crate) fn am_status(repo: &Repository) -> Res<Option<AmStatus>> {
+    let dir = repo.path().join("rebase-apply");
+    // `git rebase --apply` uses the same directory, `applying` is only there for `git am`
+    if !dir.join("applying").exists() {
+        return Ok(None);
+    }
+
+    let read_number = |file: &str| -> Res<usize> {
+        Ok(fs::read_to_string(dir.join(file))
+            .map_err(Error::ReadAmStatusFile)?
+            .trim()
+            .parse()
+            .unwrap_or(0))
+    };
+
+    Ok(Some(AmStatus {
+        current: read_number("next")?,
+        total: read_number("last")?,
+    }))
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct CherryPickStatus {
     pub head: String,
@@ -118,8 +203,7 @@ pub(crate) struct CherryPickStatus {
 
 pub(crate) fn cherry_pick_status(repo: &Repository) -> Res<Option<CherryPickStatus>> {
     let dir = repo.workdir().expect("No workdir");
-    let mut cherry_pick_head_file = dir.to_path_buf();
-    cherry_pick_head_file.push(".git/CHERRY_PICK_HEAD");
+    let cherry_pick_head_file = repo.path().join("CHERRY_PICK_HEAD");
 
     match fs::read_to_string(&cherry_pick_head_file) {
         Ok(content) => {
@@ -139,6 +223,54 @@ pub(crate) fn cherry_pick_status(repo: &Repository) -> Res<Option<CherryPickStat
     }
 }
 
+pub(crate) fn bisect_status(repo: &Repository) -> Res<Option<BisectStatus>> {
+    if !repo.path().join("BISECT_START").exists() {
+        return Ok(None);
+    }
+
+    let goods = repo
+        .references_glob("refs/bisect/good-*")
+        .map_err(Error::ListGitReferences)?
+        .names()
+        .filter_map(|name| name.ok().map(String::from))
+        .collect::<Vec<_>>();
+
+    let has_bad = repo.find_reference("refs/bisect/bad").is_ok();
+    if !has_bad || goods.is_empty() {
+        return Ok(Some(BisectStatus::Started));
+    }
+
+    let out = Command::new("git")
+        .current_dir(repo.workdir().ok_or(Error::NoRepoWorkdir)?)
+        .args(["rev-list", "--bisect-vars", "refs/bisect/bad", "--not"])
+        .args(goods)
+        .output()
+        .map_err(Error::ReadBisectStatus)?
+        .stdout;
+
+    let out = String::from_utf8_lossy(&out);
+    let var = |name: &str| {
+        out.lines()
+            .find_map(|line| line.strip_prefix(name)?.strip_prefix('='))
+            .map(|value| value.trim_matches('\''))
+    };
+    let number = |name: &str| var(name).and_then(|value| value.parse::<u32>().ok());
+
+    Ok(Some(match number("bisect_all") {
+        Some(1) => BisectStatus::FoundFirstBad(
+            var("bisect_rev")
+                .unwrap_or_default()
+                .chars()
+                .take(7)
+                .collect(),
+        ),
+        _ => BisectStatus::Bisecting {
+            revisions_left: number("bisect_nr").unwrap_or(0),
+            steps: number("bisect_steps").unwrap_or(0),
+        },
+    }))
+}
+
 fn branch_name_lossy(dir: &Path, hash: &str) -> Res<Option<String>> {
     let out = Command::new("git")
         .args(["for-each-ref", "--format", "%(objectname) %(refname:short)"])
@@ -167,6 +299,7 @@ pub(crate) fn diff_unstaged(repo: &Repository) -> Res<Diff> {
     Ok(Diff {
         file_diffs: gitu_diff::Parser::new(&text).parse_diff().unwrap(),
         diff_type: DiffType::WorkdirToIndex,
+        new_rev: None,
         text,
     })
 }
@@ -185,6 +318,27 @@ pub(crate) fn diff_staged(repo: &Repository) -> Res<Diff> {
     Ok(Diff {
         file_diffs: gitu_diff::Parser::new(&text).parse_diff().unwrap(),
         diff_type: DiffType::IndexToTree,
+        new_rev: None,
+        text,
+    })
+}
+
+pub(crate) fn diff(repo: &Repository, diff_type: DiffType) -> Res<Diff> {
+    let text = String::from_utf8_lossy(
+        &Command::new("git")
+            .current_dir(repo.workdir().expect("Bare repos unhandled"))
+            .args(["diff", "--no-ext-diff"])
+            .args(diff_type.args())
+            .output()
+            .map_err(Error::GitDiff)?
+            .stdout,
+    )
+    .into_owned();
+
+    Ok(Diff {
+        file_diffs: gitu_diff::Parser::new(&text).parse_diff().unwrap(),
+        new_rev: diff_type.new_rev(),
+        diff_type,
         text,
     })
 }
@@ -217,6 +371,7 @@ pub(crate) fn show(repo: &Repository, reference: &str) -> Res<Diff> {
     Ok(Diff {
         file_diffs: gitu_diff::Parser::new(&text).parse_diff().unwrap(),
         diff_type: DiffType::TreeToTree,
+        new_rev: Some(reference.to_string()),
         text,
     })
 }
@@ -253,6 +408,7 @@ pub(crate) fn stash_diffs(repo: &Repository, stash_ref: &str) -> Res<StashDiffs>
         Ok(Diff {
             file_diffs: gitu_diff::Parser::new(&text).parse_diff().unwrap(),
             diff_type: DiffType::TreeToTree,
+            new_rev: Some(to.to_string()),
             text,
         })
     };
@@ -271,6 +427,7 @@ pub(crate) fn stash_diffs(repo: &Repository, stash_ref: &str) -> Res<StashDiffs>
         Ok(Diff {
             file_diffs: gitu_diff::Parser::new(&text).parse_diff().unwrap(),
             diff_type: DiffType::TreeToTree,
+            new_rev: Some(stash_ref.to_string()),
             text,
         })
     };
@@ -279,6 +436,7 @@ pub(crate) fn stash_diffs(repo: &Repository, stash_ref: &str) -> Res<StashDiffs>
         let empty = Diff {
             text: String::new(),
             diff_type: DiffType::TreeToTree,
+            new_rev: None,
             file_diffs: vec![],
         };
         return Ok(StashDiffs {
@@ -328,6 +486,127 @@ pub(crate) fn stash_diffs(repo: &Repository, stash_ref: &str) -> Res<StashDiffs>
     })
 }
 
+/// Lists the commits `git log` selects given `args`, for filters libgit2 has no equivalent of.
+pub(crate) fn log_oids(
+    repo: &Repository,
+    rev: Option<Oid>,
+    args: &[String],
+    paths: &[String],
+) -> Res<HashSet<Oid>> {
+    let output = Command::new("git")
+        .current_dir(repo.workdir().expect("Bare repos unhandled"))
+        .args(["log", "--format=%H"])
+        .args(args)
+        .arg(rev.map(|oid| oid.to_string()).unwrap_or("HEAD".to_string()))
+        .arg("--")
+        .args(paths)
+        .output()
+        .map_err(Error::GitLog)?;
+
+    if !output.status.success() {
+        return Err(Error::GitLogFailed(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| Oid::from_str(line).ok())
+        .collect())
+}
+
+/// The commits that touched `path`, following renames, each with the file's diff in it.
+pub(crate) fn file_log(repo: &Repository, path: &str) -> Res<Vec<Diff>> {
+    let output = Command::new("git")
+        .current_dir(repo.workdir().expect("Bare repos unhandled"))
+        .args([
+            "log",
+            "--follow",
+            "--patch",
+            "--no-ext-diff",
+            "--format=%x00%H",
+            "--",
+        ])
+        .arg(path)
+        .output()
+        .map_err(Error::GitLog)?;
+
+    if !output.status.success() {
+        return Err(Error::GitLogFailed(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .split('\0')
+        .filter_map(|entry| entry.split_once('\n'))
+        .map(|(oid, text)| Diff {
+            file_diffs: gitu_diff::Parser::new(text).parse_diff().unwrap(),
+            diff_type: DiffType::TreeToTree,
+            new_rev: Some(oid.to_string()),
+            text: text.to_string(),
+        })
+        .collect())
+}
+
+/// The paths `git clean` would remove with the given flags, from `git clean -n`.
+pub(crate) fn clean_preview(
+    repo: &Repository,
+    args: &[String],
+    paths: &[String],
+) -> Res<Vec<String>> {
+    let output = Command::new("git")
+        .current_dir(repo.workdir().expect("Bare repos unhandled"))
+        // The output is parsed, so it mustn't be translated
+        .env("LC_ALL", "C")
+        .args(["clean", "-n"])
+        .args(args)
+        .arg("--")
+        .args(paths)
+        .output()
+        .map_err(Error::GitClean)?;
+
+    if !output.status.success() {
+        return Err(Error::GitCleanFailed(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| line.strip_prefix("Would remove "))
+        .map(String::from)
+        .collect())
+}
+
+/// Runs `git apply --check --cached` on a patch the user may have edited, recounting its hunk headers.
+pub(crate) fn check_cached_patch(repo: &Repository, patch: &[u8]) -> Res<()> {
+    let mut child = Command::new("git")
+        .current_dir(repo.workdir().expect("Bare repos unhandled"))
+        .args(["apply", "--check", "--cached", "--recount"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(Error::GitApply)?;
+
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(patch)
+        .map_err(Error::GitApply)?;
+
+    let output = child.wait_with_output().map_err(Error::GitApply)?;
+    if !output.status.success() {
+        return Err(Error::EditedPatchInvalid(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
 pub(crate) fn show_summary(repo: &Repository, reference: &str) -> Res<Commit> {
     let object = &repo
         .revparse_single(reference)
@@ -391,6 +670,20 @@ pub(crate) fn get_current_branch(repo: &git2::Repository) -> Res<Branch<'_>> {
     }
 }
 
+/// Reads `branch.<current branch>.<key>`, without falling back to any other setting.
+pub(crate) fn get_branch_config(repo: &git2::Repository, key: &str) -> Res<Option<String>> {
+    let branch = get_current_branch_name(repo)?;
+    let config = repo.config().map_err(Error::ReadGitConfig)?;
+
+    match config.get_entry(&format!("branch.{branch}.{key}")) {
+        Ok(entry) => Ok(Some(
+            String::from_utf8_lossy(entry.value_bytes()).into_owned(),
+        )),
+        Err(e) if e.code() == git2::ErrorCode::NotFound => Ok(None),
+        Err(e) => Err(Error::ReadGitConfig(e)),
+    }
+}
+
 pub(crate) fn is_branch_merged(repo: &git2::Repository, name: &str) -> Res<bool> {
     let branch = repo
         .find_branch(name, git2::BranchType::Local)
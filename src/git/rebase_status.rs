@@ -1,5 +1,12 @@
+use super::rebase_todo::TodoLine;
+
 #[derive(Debug, Clone)]
 pub(crate) struct RebaseStatus {
     pub onto: String,
+    pub onto_oid: String,
     pub head_name: String,
+    pub done: Vec<TodoLine>,
+    pub todo: Vec<TodoLine>,
+    /// Whether git stopped at the last `done` line (an `edit` or a conflict)
+    pub stopped: bool,
 }
@@ -0,0 +1,185 @@
+use crate::{Res, error::Error};
+use git2::Repository;
+use std::{fmt::Display, fs, io, path::PathBuf};
+
+/// Sequence editor that makes `git rebase -i` stop before applying anything,
+/// leaving the todo list in `.git/rebase-merge/git-rebase-todo` for Gitu to edit.
+pub(crate) const BREAK_SEQUENCE_EDITOR: &str =
+    r#"f() { { echo break; cat "$1"; } > "$1.tmp" && mv "$1.tmp" "$1"; }; f"#;
+
+/// Sequence editor that replaces the `pick` of the first todo line with another action.
+/// Rebasing onto a commit's parent puts that commit first.
+/// Git writes `p` instead when `rebase.abbreviateCommands` is set.
+pub(crate) fn first_line_sequence_editor(action: TodoAction) -> String {
+    format!(
+        r#"f() {{ sed -E "1s/^(pick|p) /{action} /" "$1" > "$1.tmp" && mv "$1.tmp" "$1"; }}; f"#
+    )
+}
+
+/// Sequence editor that swaps the first two todo lines.
+/// Rebasing onto the parent of the older of two adjacent commits puts them first.
+pub(crate) const SWAP_SEQUENCE_EDITOR: &str = r#"f() { { sed -n 2p "$1"; sed -n 1p "$1"; sed 1,2d "$1"; } > "$1.tmp" && mv "$1.tmp" "$1"; }; f"#;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TodoAction {
+    Pick,
+    Reword,
+    Edit,
+    Squash,
+    Fixup,
+    Drop,
+}
+
+impl TodoAction {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            TodoAction::Pick => "pick",
+            TodoAction::Reword => "reword",
+            TodoAction::Edit => "edit",
+            TodoAction::Squash => "squash",
+            TodoAction::Fixup => "fixup",
+            TodoAction::Drop => "drop",
+        }
+    }
+
+    fn parse(word: &str) -> Option<Self> {
+        Some(match word {
+            "p" | "pick" => TodoAction::Pick,
+            "r" | "reword" => TodoAction::Reword,
+            "e" | "edit" => TodoAction::Edit,
+            "s" | "squash" => TodoAction::Squash,
+            "f" | "fixup" => TodoAction::Fixup,
+            "d" | "drop" => TodoAction::Drop,
+            _ => return None,
+        })
+    }
+}
+
+impl Display for TodoAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum TodoLine {
+    Commit {
+        action: TodoAction,
+        oid: String,
+        summary: String,
+    },
+    Exec(String),
+    /// Any other command (`break`, `label`, `merge`, ...), kept as written
+    Other(String),
+}
+
+impl Display for TodoLine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TodoLine::Commit {
+                action,
+                oid,
+                summary,
+            } => write!(f, "{action} {oid} {summary}"),
+            TodoLine::Exec(command) => write!(f, "exec {command}"),
+            TodoLine::Other(line) => f.write_str(line),
+        }
+    }
+}
+
+pub(crate) fn todo_path(repo: &Repository) -> PathBuf {
+    repo.path().join("rebase-merge/git-rebase-todo")
+}
+
+pub(crate) fn read(repo: &Repository) -> Res<Vec<TodoLine>> {
+    let text = fs::read_to_string(todo_path(repo)).map_err(Error::ReadRebaseTodo)?;
+    Ok(parse(&text))
+}
+
+/// Reads the lines already carried out, the last one being the current.
+pub(crate) fn read_done(repo: &Repository) -> Res<Vec<TodoLine>> {
+    match fs::read_to_string(repo.path().join("rebase-merge/done")) {
+        Ok(text) => Ok(parse(&text)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+        Err(err) => Err(Error::ReadRebaseTodo(err)),
+    }
+}
+
+pub(crate) fn write(repo: &Repository, lines: &[TodoLine]) -> Res<()> {
+    fs::write(todo_path(repo), format(lines)).map_err(Error::WriteRebaseTodo)
+}
+
+pub(crate) fn parse(text: &str) -> Vec<TodoLine> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(parse_line)
+        .collect()
+}
+
+fn parse_line(line: &str) -> TodoLine {
+    let (command, rest) = line.split_once(' ').unwrap_or((line, ""));
+
+    if let Some(action) = TodoAction::parse(command) {
+        let (oid, summary) = rest.split_once(' ').unwrap_or((rest, ""));
+
+        // `fixup -C <oid>` and the like carry options we don't model
+        if !oid.is_empty() && !oid.starts_with('-') {
+            return TodoLine::Commit {
+                action,
+                oid: oid.to_string(),
+                summary: summary.to_string(),
+            };
+        }
+    }
+
+    match command {
+        "x" | "exec" => TodoLine::Exec(rest.to_string()),
+        _ => TodoLine::Other(line.to_string()),
+    }
+}
+
+fn format(lines: &[TodoLine]) -> String {
+    lines.iter().map(|line| format!("{line}\n")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_todo() {
+        let text = "pick 1111111 first commit\n\
+                    s 2222222 second\n\
+                    exec make test\n\
+                    fixup -C 3333333 third\n\
+                    break\n\
+                    \n\
+                    # Rebase 0000000..3333333 onto 0000000\n";
+
+        assert_eq!(
+            parse(text),
+            vec![
+                TodoLine::Commit {
+                    action: TodoAction::Pick,
+                    oid: "1111111".into(),
+                    summary: "first commit".into(),
+                },
+                TodoLine::Commit {
+                    action: TodoAction::Squash,
+                    oid: "2222222".into(),
+                    summary: "second".into(),
+                },
+                TodoLine::Exec("make test".into()),
+                TodoLine::Other("fixup -C 3333333 third".into()),
+                TodoLine::Other("break".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn format_todo() {
+        let lines = parse("p 1111111 first\nx echo hi\n");
+        assert_eq!(format(&lines), "pick 1111111 first\nexec echo hi\n");
+    }
+}
@@ -81,6 +81,42 @@ pub(crate) fn get_upstream_remote(repo: &Repository) -> Res<Option<String>> {
     }
 }
 
+/// Splits a remote-tracking branch like `origin/feature` into the remote and the branch on it.
+/// Returns `None` if there's no such remote-tracking branch.
+pub(crate) fn split_remote_branch(
+    repo: &Repository,
+    shorthand: &str,
+) -> Res<Option<(String, String)>> {
+    let refname = format!("refs/remotes/{shorthand}");
+    if repo.find_reference(&refname).is_err() {
+        return Ok(None);
+    }
+
+    let remote = String::from_utf8_lossy(
+        repo.branch_remote_name(&refname)
+            .map_err(Error::GetRemote)?
+            .deref(),
+    )
+    .into_owned();
+
+    let branch = shorthand
+        .strip_prefix(&format!("{remote}/"))
+        .unwrap_or(shorthand)
+        .to_string();
+
+    Ok(Some((remote, branch)))
+}
+
+pub(crate) fn remote_names(repo: &Repository) -> Res<Vec<String>> {
+    Ok(repo
+        .remotes()
+        .map_err(Error::GetRemote)?
+        .iter()
+        .flatten()
+        .map(String::from)
+        .collect())
+}
+
 pub(crate) fn get_push_remote(repo: &Repository) -> Res<Option<String>> {
     let push_remote_cfg = head_push_remote_cfg(repo)?;
     let config = repo.config().map_err(Error::ReadGitConfig)?;
@@ -0,0 +1,10 @@
+/// A commit being split, while the rebase is stopped with its changes unstaged.
+#[derive(Debug, Clone)]
+pub(crate) struct SplitStatus {
+    /// Short id of the commit being split
+    pub commit: String,
+    /// How many commits have been created from its changes so far
+    pub created: usize,
+    /// Whether the commits created so far add up to the commit being split
+    pub done: bool,
+}
@@ -1,3 +1,5 @@
+use std::fmt::Display;
+
 #[derive(Debug, PartialEq, Eq)]
 pub(crate) struct Status {
     pub branch_status: BranchStatus,
@@ -23,4 +25,59 @@ impl StatusFile {
     pub fn is_untracked(&self) -> bool {
         self.status_code == ['?', '?']
     }
+
+    pub fn conflict(&self) -> Option<Conflict> {
+        match self.status_code {
+            ['D', 'D'] => Some(Conflict::BothDeleted),
+            ['A', 'U'] => Some(Conflict::AddedByUs),
+            ['U', 'D'] => Some(Conflict::DeletedByThem),
+            ['U', 'A'] => Some(Conflict::AddedByThem),
+            ['D', 'U'] => Some(Conflict::DeletedByUs),
+            ['A', 'A'] => Some(Conflict::BothAdded),
+            ['U', 'U'] => Some(Conflict::BothModified),
+            _ => None,
+        }
+    }
+}
+
+/// How a path ended up unmerged, as told by its `git status` code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Conflict {
+    BothDeleted,
+    AddedByUs,
+    DeletedByThem,
+    AddedByThem,
+    DeletedByUs,
+    BothAdded,
+    BothModified,
+}
+
+impl Conflict {
+    pub(crate) fn has_ours(&self) -> bool {
+        !matches!(
+            self,
+            Conflict::BothDeleted | Conflict::DeletedByUs | Conflict::AddedByThem
+        )
+    }
+
+    pub(crate) fn has_theirs(&self) -> bool {
+        !matches!(
+            self,
+            Conflict::BothDeleted | Conflict::DeletedByThem | Conflict::AddedByUs
+        )
+    }
+}
+
+impl Display for Conflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(match self {
+            Conflict::BothDeleted => "both deleted",
+            Conflict::AddedByUs => "added by us",
+            Conflict::DeletedByThem => "deleted by them",
+            Conflict::AddedByThem => "added by them",
+            Conflict::DeletedByUs => "deleted by us",
+            Conflict::BothAdded => "both added",
+            Conflict::BothModified => "both modified",
+        })
+    }
 }
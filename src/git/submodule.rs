@@ -0,0 +1,48 @@
+use crate::{Res, error::Error};
+use git2::{Oid, Repository, SubmoduleIgnore};
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone)]
+pub(crate) struct SubmoduleStatus {
+    pub path: PathBuf,
+    /// The commit recorded in the superproject's HEAD
+    pub recorded: Option<Oid>,
+    /// The commit checked out in the submodule, `None` if it isn't initialized
+    pub checked_out: Option<Oid>,
+    /// Whether the submodule has modified or untracked files
+    pub dirty: bool,
+}
+
+pub(crate) fn list(repo: &Repository) -> Res<Vec<SubmoduleStatus>> {
+    repo.submodules()
+        .map_err(Error::ListSubmodules)?
+        .iter()
+        .map(|submodule| {
+            let status = repo
+                .submodule_status(
+                    &String::from_utf8_lossy(submodule.name_bytes()),
+                    SubmoduleIgnore::None,
+                )
+                .map_err(Error::ListSubmodules)?;
+
+            Ok(SubmoduleStatus {
+                path: submodule.path().to_path_buf(),
+                recorded: submodule.head_id(),
+                checked_out: if status.is_wd_uninitialized() {
+                    None
+                } else {
+                    submodule.workdir_id()
+                },
+                dirty: status.contains(git2::SubmoduleStatus::WD_INDEX_MODIFIED)
+                    || status.is_wd_wd_modified()
+                    || status.is_wd_untracked(),
+            })
+        })
+        .collect()
+}
+
+/// Opens the repository of the submodule checked out at `path` (relative to the workdir).
+pub(crate) fn open(repo: &Repository, path: &Path) -> Res<Repository> {
+    let workdir = repo.workdir().ok_or(Error::NoRepoWorkdir)?;
+    Repository::open(workdir.join(path)).map_err(Error::OpenSubmodule)
+}
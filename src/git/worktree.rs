@@ -0,0 +1,139 @@
+use crate::{Res, error::Error};
+use git2::Repository;
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Worktree {
+    pub path: PathBuf,
+    pub head: Option<String>,
+    pub branch: Option<String>,
+    pub bare: bool,
+    pub prunable: bool,
+}
+
+/// Lists all worktrees of the repository, the main worktree first.
+pub(crate) fn list(repo: &Repository) -> Res<Vec<Worktree>> {
+    let out = Command::new("git")
+        .current_dir(repo.workdir().ok_or(Error::NoRepoWorkdir)?)
+        .args(["worktree", "list", "--porcelain"])
+        .output()
+        .map_err(Error::ListWorktrees)?
+        .stdout;
+
+    Ok(parse_porcelain(&String::from_utf8_lossy(&out)))
+}
+
+/// Lists the worktrees other than the one `repo` is opened at.
+pub(crate) fn list_others(repo: &Repository) -> Res<Vec<Worktree>> {
+    let current = repo.workdir().ok_or(Error::NoRepoWorkdir)?;
+
+    Ok(list(repo)?
+        .into_iter()
+        .filter(|worktree| !worktree.bare && !is_same_dir(&worktree.path, current))
+        // Submodules report their git dir (`.git/modules/<name>`) as the main worktree
+        .filter(|worktree| !is_same_dir(&worktree.path, repo.commondir()))
+        .collect())
+}
+
+fn is_same_dir(a: &Path, b: &Path) -> bool {
+    match (a.canonicalize(), b.canonicalize()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a == b,
+    }
+}
+
+fn parse_porcelain(text: &str) -> Vec<Worktree> {
+    text.split("\n\n")
+        .filter_map(|record| {
+            let mut lines = record.lines();
+            let path = lines.next()?.strip_prefix("worktree ")?;
+
+            let mut worktree = Worktree {
+                path: PathBuf::from(path),
+                head: None,
+                branch: None,
+                bare: false,
+                prunable: false,
+            };
+
+            for line in lines {
+                if let Some(head) = line.strip_prefix("HEAD ") {
+                    worktree.head = Some(head.to_string());
+                } else if let Some(branch) = line.strip_prefix("branch ") {
+                    worktree.branch = Some(
+                        branch
+                            .strip_prefix("refs/heads/")
+                            .unwrap_or(branch)
+                            .to_string(),
+                    );
+                } else if line == "bare" {
+                    worktree.bare = true;
+                } else if line.starts_with("prunable") {
+                    worktree.prunable = true;
+                }
+            }
+
+            Some(worktree)
+        })
+        .collect()
+}
+
+/// Formats a worktree path relative to `base` when it's a sibling or a child of it.
+pub(crate) fn display_path(path: &Path, base: &Path) -> String {
+    let base = base.canonicalize().unwrap_or(base.to_path_buf());
+    let path = path.canonicalize().unwrap_or(path.to_path_buf());
+
+    if let Ok(relative) = path.strip_prefix(&base) {
+        return relative.to_string_lossy().into_owned();
+    }
+
+    if let Some(relative) = base.parent().and_then(|p| path.strip_prefix(p).ok()) {
+        return Path::new("..")
+            .join(relative)
+            .to_string_lossy()
+            .into_owned();
+    }
+
+    path.to_string_lossy().into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_worktree_list() {
+        let text = "worktree /repo\n\
+                    HEAD 1111111111111111111111111111111111111111\n\
+                    branch refs/heads/main\n\
+                    \n\
+                    worktree /repo-feature\n\
+                    HEAD 2222222222222222222222222222222222222222\n\
+                    detached\n\
+                    prunable gitdir file points to non-existent location\n\
+                    \n";
+
+        assert_eq!(
+            parse_porcelain(text),
+            vec![
+                Worktree {
+                    path: PathBuf::from("/repo"),
+                    head: Some("1111111111111111111111111111111111111111".into()),
+                    branch: Some("main".into()),
+                    bare: false,
+                    prunable: false,
+                },
+                Worktree {
+                    path: PathBuf::from("/repo-feature"),
+                    head: Some("2222222222222222222222222222222222222222".into()),
+                    branch: None,
+                    bare: false,
+                    prunable: true,
+                },
+            ]
+        );
+    }
+}
@@ -31,6 +31,56 @@ pub struct FileDiff {
     pub hunks: Vec<Hunk>,
 }
 
+/// The commits of a submodule before and after, as given by git's `Subproject commit` lines.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SubmoduleChange {
+    pub old: Option<Range<usize>>,
+    pub new: Option<Range<usize>>,
+    /// The submodule has uncommitted changes (`-dirty` suffix)
+    pub dirty: bool,
+}
+
+impl FileDiff {
+    /// Returns the old and new commit if this is a diff of a submodule rather than of a file.
+    pub fn submodule_change(&self, input: &str) -> Option<SubmoduleChange> {
+        const OLD_PREFIX: &str = "-Subproject commit ";
+        const NEW_PREFIX: &str = "+Subproject commit ";
+
+        let [hunk] = &self.hunks[..] else {
+            return None;
+        };
+
+        let mut change = SubmoduleChange {
+            old: None,
+            new: None,
+            dirty: false,
+        };
+
+        let mut line_start = hunk.content.range.start;
+        for line in input[hunk.content.range.clone()].split_inclusive('\n') {
+            let hash_start = line_start + OLD_PREFIX.len();
+            let mut hash = line.get(OLD_PREFIX.len()..)?.trim_end();
+            line_start += line.len();
+
+            if let Some(stripped) = hash.strip_suffix("-dirty") {
+                hash = stripped;
+                change.dirty = true;
+            }
+
+            let range = Some(hash_start..hash_start + hash.len());
+            if line.starts_with(OLD_PREFIX) {
+                change.old = range;
+            } else if line.starts_with(NEW_PREFIX) {
+                change.new = range;
+            } else {
+                return None;
+            }
+        }
+
+        Some(change)
+    }
+}
+
 #[allow(dead_code)]
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum Status {
@@ -1043,6 +1093,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn submodule() {
+        let input = "diff --git a/sub b/sub\n\
+            index 25e1f6e..8858c96 160000\n\
+            --- a/sub\n\
+            +++ b/sub\n\
+            @@ -1 +1 @@\n\
+            -Subproject commit 25e1f6e244fe4143deaa033f52c7221b1208dcf0\n\
+            +Subproject commit 8858c9694727e4f0140566e2d8c6be0bdb70786a-dirty\n";
+
+        let mut parser = Parser::new(input);
+        let diffs = parser.parse_diff().unwrap();
+        let change = diffs[0].submodule_change(input).unwrap();
+        assert_eq!(
+            &input[change.old.unwrap()],
+            "25e1f6e244fe4143deaa033f52c7221b1208dcf0"
+        );
+        assert_eq!(
+            &input[change.new.unwrap()],
+            "8858c9694727e4f0140566e2d8c6be0bdb70786a"
+        );
+        assert!(change.dirty);
+    }
+
+    #[test]
+    fn not_a_submodule() {
+        let input = "diff --git a/file b/file\n\
+            --- a/file\n\
+            +++ b/file\n\
+            @@ -1 +1 @@\n\
+            -Subproject commit 25e1f6e244fe4143deaa033f52c7221b1208dcf0\n\
+            +something else\n";
+
+        let mut parser = Parser::new(input);
+        let diffs = parser.parse_diff().unwrap();
+        assert_eq!(diffs[0].submodule_change(input), None);
+    }
+
     #[test]
     fn empty_commit() {
         let input = "commit 6c9991b0006b38b439605eb68baff05f0c0ebf95\nAuthor: altsem <alltidsemester@pm.me>\nDate:   Sun Jun 16 19:01:00 2024 +0200\n\n    feat: -n argument to limit log\n            \n        ";
@@ -0,0 +1,243 @@
+use crate::config::GraphStyleConfig;
+use git2::Oid;
+use ratatui::{style::Style, text::Span};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Glyph {
+    Empty,
+    Commit,
+    Vertical,
+    Horizontal,
+    /// A horizontal connection crossing a lane
+    Cross,
+    /// A connection joining a lane that passes through, from the left or the right
+    TeeLeft,
+    TeeRight,
+    /// A lane starting here, for a parent of a merge
+    ForkLeft,
+    ForkRight,
+    /// A lane ending here, its commit having several children
+    MergeLeft,
+    MergeRight,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct GraphCell {
+    pub glyph: Glyph,
+    /// Picks the color of the cell
+    pub lane: usize,
+}
+
+/// Lays out commits in lanes, one row per commit, in the order they're walked.
+/// Commits are expected in topological order, children before their parents.
+#[derive(Default)]
+pub(crate) struct Graph {
+    /// The commit each lane is heading towards
+    lanes: Vec<Option<Oid>>,
+}
+
+impl Graph {
+    pub(crate) fn next_row(&mut self, oid: Oid, parents: &[Oid]) -> Vec<GraphCell> {
+        let col = match self.lanes.iter().position(|lane| *lane == Some(oid)) {
+            Some(col) => col,
+            None => self.free_lane(),
+        };
+
+        let mut glyphs: Vec<Glyph> = self
+            .lanes
+            .iter()
+            .map(|lane| match lane {
+                Some(_) => Glyph::Vertical,
+                None => Glyph::Empty,
+            })
+            .collect();
+        glyphs[col] = Glyph::Commit;
+
+        // Lanes from other children of this commit end here
+        let mut connections = vec![];
+        for (i, lane) in self.lanes.iter_mut().enumerate() {
+            if i != col && *lane == Some(oid) {
+                *lane = None;
+                glyphs[i] = if i < col {
+                    Glyph::MergeLeft
+                } else {
+                    Glyph::MergeRight
+                };
+                connections.push(i);
+            }
+        }
+
+        self.lanes[col] = parents.first().copied();
+
+        for &parent in parents.iter().skip(1) {
+            let (i, joins_existing) = match self.lanes.iter().position(|lane| *lane == Some(parent))
+            {
+                Some(i) => (i, true),
+                None => {
+                    let i = self.free_lane();
+                    self.lanes[i] = Some(parent);
+                    (i, false)
+                }
+            };
+
+            glyphs.resize(self.lanes.len(), Glyph::Empty);
+            glyphs[i] = match (joins_existing && glyphs[i] == Glyph::Vertical, i < col) {
+                (true, true) => Glyph::TeeLeft,
+                (true, false) => Glyph::TeeRight,
+                (false, true) => Glyph::ForkLeft,
+                (false, false) => Glyph::ForkRight,
+            };
+            connections.push(i);
+        }
+
+        while self.lanes.last() == Some(&None) {
+            self.lanes.pop();
+        }
+
+        let left = connections.iter().copied().chain([col]).min().unwrap();
+        let right = connections.iter().copied().chain([col]).max().unwrap();
+
+        let mut cells = vec![];
+        for (i, glyph) in glyphs.into_iter().enumerate() {
+            let crossed = left < i && i < right && i != col;
+            let connection_lane = if i < col { left } else { right };
+
+            cells.push(match glyph {
+                Glyph::Empty if crossed => GraphCell {
+                    glyph: Glyph::Horizontal,
+                    lane: connection_lane,
+                },
+                Glyph::Vertical if crossed => GraphCell {
+                    glyph: Glyph::Cross,
+                    lane: connection_lane,
+                },
+                glyph => GraphCell { glyph, lane: i },
+            });
+
+            // Spacing between lanes, part of the connection if it spans past them
+            cells.push(GraphCell {
+                glyph: if left <= i && i < right {
+                    Glyph::Horizontal
+                } else {
+                    Glyph::Empty
+                },
+                lane: connection_lane,
+            });
+        }
+
+        while cells.last().is_some_and(|cell| cell.glyph == Glyph::Empty) {
+            cells.pop();
+        }
+
+        cells
+    }
+
+    fn free_lane(&mut self) -> usize {
+        match self.lanes.iter().position(Option::is_none) {
+            Some(i) => i,
+            None => {
+                self.lanes.push(None);
+                self.lanes.len() - 1
+            }
+        }
+    }
+}
+
+pub(crate) fn to_spans(cells: Vec<GraphCell>, config: &GraphStyleConfig) -> Vec<Span<'static>> {
+    cells
+        .into_iter()
+        .map(|cell| {
+            let lane_style = match config.lanes.len() {
+                0 => Style::new(),
+                n => Style::from(&config.lanes[cell.lane % n]),
+            };
+
+            let style = match cell.glyph {
+                Glyph::Commit => lane_style.patch(&config.commit),
+                _ => lane_style,
+            };
+
+            Span::styled(symbol(cell.glyph, config.ascii), style)
+        })
+        .collect()
+}
+
+fn symbol(glyph: Glyph, ascii: bool) -> &'static str {
+    match (glyph, ascii) {
+        (Glyph::Empty, _) => " ",
+        (Glyph::Commit, false) => "●",
+        (Glyph::Commit, true) => "*",
+        (Glyph::Vertical, false) => "│",
+        (Glyph::Vertical, true) => "|",
+        (Glyph::Horizontal, false) => "─",
+        (Glyph::Horizontal, true) => "-",
+        (Glyph::Cross, false) => "┼",
+        (Glyph::Cross, true) => "+",
+        (Glyph::TeeLeft, false) => "├",
+        (Glyph::TeeRight, false) => "┤",
+        (Glyph::TeeLeft | Glyph::TeeRight, true) => "+",
+        (Glyph::ForkLeft, false) => "╭",
+        (Glyph::ForkRight, false) => "╮",
+        (Glyph::ForkLeft | Glyph::ForkRight, true) => ".",
+        (Glyph::MergeLeft, false) => "╰",
+        (Glyph::MergeRight, false) => "╯",
+        (Glyph::MergeLeft | Glyph::MergeRight, true) => "'",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn oid(n: u8) -> Oid {
+        Oid::from_bytes(&[n; 20]).unwrap()
+    }
+
+    fn render(graph: &mut Graph, commit: u8, parents: &[u8]) -> String {
+        let parents = parents.iter().copied().map(oid).collect::<Vec<_>>();
+        graph
+            .next_row(oid(commit), &parents)
+            .into_iter()
+            .map(|cell| symbol(cell.glyph, false))
+            .collect()
+    }
+
+    #[test]
+    fn linear() {
+        let mut graph = Graph::default();
+        assert_eq!(render(&mut graph, 3, &[2]), "●");
+        assert_eq!(render(&mut graph, 2, &[1]), "●");
+        assert_eq!(render(&mut graph, 1, &[]), "●");
+    }
+
+    #[test]
+    fn merge() {
+        // 5 merges 4 (on a branch off 2) into 3
+        let mut graph = Graph::default();
+        assert_eq!(render(&mut graph, 5, &[3, 4]), "●─╮");
+        assert_eq!(render(&mut graph, 4, &[2]), "│ ●");
+        assert_eq!(render(&mut graph, 3, &[2]), "● │");
+        assert_eq!(render(&mut graph, 2, &[1]), "●─╯");
+        assert_eq!(render(&mut graph, 1, &[]), "●");
+    }
+
+    #[test]
+    fn diverged_branches() {
+        // Two branch tips 4 and 3, both children of 2
+        let mut graph = Graph::default();
+        assert_eq!(render(&mut graph, 4, &[2]), "●");
+        assert_eq!(render(&mut graph, 3, &[2]), "│ ●");
+        assert_eq!(render(&mut graph, 2, &[1]), "●─╯");
+    }
+
+    #[test]
+    fn merge_crossing_lane() {
+        // 6 merges 3, and 5 branches off 1 further down
+        let mut graph = Graph::default();
+        assert_eq!(render(&mut graph, 7, &[4]), "●");
+        assert_eq!(render(&mut graph, 6, &[4, 3]), "│ ●─╮");
+        assert_eq!(render(&mut graph, 5, &[1]), "│ │ │ ●");
+        assert_eq!(render(&mut graph, 4, &[3]), "●─╯ │ │");
+        assert_eq!(render(&mut graph, 3, &[1]), "●───╯ │");
+    }
+}
@@ -1,6 +1,7 @@
 use crate::config::Config;
 use crate::config::DiffHighlightConfig;
 use crate::config::SyntaxHighlightConfig;
+use crate::git::blame::Blame;
 use crate::git::diff::Diff;
 use crate::gitu_diff;
 use crate::syntax_parser;
@@ -70,6 +71,37 @@ pub(crate) fn highlight_hunk(
     Arc::new(highlights)
 }
 
+#[cached(
+    ty = "SizedCache<u64, Arc<HunkHighlights>>",
+    create = "{ SizedCache::with_size(20) }",
+    convert = r#"{ _blame_hash }"#
+)]
+pub(crate) fn highlight_blame(
+    _blame_hash: u64,
+    config: &Config,
+    blame: &Blame,
+) -> Arc<HunkHighlights> {
+    let mut highlights_iterator = iter_syntax_highlights(
+        &config.style.syntax_highlight,
+        &blame.path,
+        blame.text.clone(),
+    );
+
+    let mut highlights = HunkHighlights {
+        spans: vec![],
+        line_index: vec![],
+    };
+
+    for line in &blame.lines {
+        let start = highlights.spans.len();
+
+        collect_line_highlights(&mut highlights_iterator, &line.range, &mut highlights.spans);
+        highlights.line_index.push(start..highlights.spans.len());
+    }
+
+    Arc::new(highlights)
+}
+
 #[derive(Clone)]
 pub struct HunkHighlights {
     spans: Vec<(Range<usize>, Style)>,
@@ -1,6 +1,18 @@
 use std::{ops::Range, path::PathBuf, rc::Rc};
 
-use crate::{Res, error::Error, git::diff::Diff};
+use crate::{
+    Res,
+    error::Error,
+    git::{
+        bisect_status::BisectStatus,
+        blame::Blame,
+        conflict::{ConflictFile, ConflictSide},
+        diff::Diff,
+        rebase_todo::TodoLine,
+        status::Conflict,
+    },
+    graph::GraphCell,
+};
 
 #[derive(Clone, Debug)]
 pub(crate) enum ItemData {
@@ -8,17 +20,37 @@ pub(crate) enum ItemData {
     AllUnstaged(usize),
     AllStaged(usize),
     AllUntracked(Vec<PathBuf>),
+    AllUnmerged(usize),
     Reference {
         prefix: &'static str,
         kind: Ref,
     },
     Commit {
+        /// Shown before the hash, e.g. the action of a rebase todo line
+        prefix: &'static str,
+        /// Lanes of the commit graph, drawn left of everything else
+        graph: Vec<GraphCell>,
         oid: String,
         short_id: String,
         associated_references: Vec<Ref>,
         summary: String,
+        /// Followed by its diff, which can be collapsed, e.g. in a file's history
+        collapsible: bool,
     },
     Untracked(PathBuf),
+    Unmerged {
+        path: PathBuf,
+        conflict: Conflict,
+    },
+    ConflictRegion {
+        file: Rc<ConflictFile>,
+        region_i: usize,
+    },
+    ConflictLine {
+        file: Rc<ConflictFile>,
+        line_range: Range<usize>,
+        side: ConflictSide,
+    },
     Delta {
         diff: Rc<Diff>,
         file_i: usize,
@@ -40,6 +72,31 @@ pub(crate) enum ItemData {
         stash_ref: String,
         id: usize,
     },
+    Submodule {
+        path: PathBuf,
+        recorded: Option<String>,
+        checked_out: Option<String>,
+        dirty: bool,
+    },
+    Worktree {
+        path: PathBuf,
+        display_path: String,
+        branch: Option<String>,
+        head: Option<String>,
+    },
+    RebaseTodoLine {
+        index: usize,
+        line: TodoLine,
+    },
+    BlameChunk {
+        blame: Rc<Blame>,
+        chunk_i: usize,
+    },
+    BlameLine {
+        blame: Rc<Blame>,
+        chunk_i: usize,
+        line_i: usize,
+    },
     Header(SectionHeader),
     BranchStatus(String, u32, u32),
     Error(String),
@@ -52,11 +109,19 @@ impl ItemData {
             ItemData::AllUnstaged(_)
                 | ItemData::AllStaged(_)
                 | ItemData::AllUntracked(_)
+                | ItemData::AllUnmerged(_)
+                | ItemData::Unmerged { .. }
+                | ItemData::ConflictRegion { .. }
                 | ItemData::Untracked(_)
                 | ItemData::Delta { .. }
                 | ItemData::Hunk { .. }
+                | ItemData::BlameChunk { .. }
                 | ItemData::Header(_)
                 | ItemData::BranchStatus(_, _, _)
+                | ItemData::Commit {
+                    collapsible: true,
+                    ..
+                }
         )
     }
 
@@ -72,6 +137,15 @@ impl ItemData {
                 .cloned()
                 .map(Rev::Ref)
                 .or_else(|| Some(Rev::Commit(oid.to_owned()))),
+            ItemData::RebaseTodoLine {
+                line: TodoLine::Commit { oid, .. },
+                ..
+            } => Some(Rev::Commit(oid.to_owned())),
+            ItemData::BlameChunk { blame, chunk_i }
+            | ItemData::BlameLine { blame, chunk_i, .. } => {
+                let chunk = &blame.chunks[*chunk_i];
+                chunk.is_committed().then(|| Rev::Commit(chunk.oid.clone()))
+            }
             _ => None,
         }
     }
@@ -147,14 +221,21 @@ pub(crate) enum SectionHeader {
     NoBranch,
     OnBranch(String),
     Rebase(String, String),
+    Split(String, usize),
     Merge(String),
     Revert(String),
     CherryPick(String),
+    Am(usize, usize),
+    Bisect(BisectStatus),
     Stashes,
+    Submodules,
+    Worktrees,
     RecentCommits,
     Commit(String),
+    Diff(String),
     StashRef(String),
     StagedChanges(usize),
     UnstagedChanges(usize),
     UntrackedFiles(usize),
+    Ignore(String),
 }
@@ -1,17 +1,24 @@
 use crate::Res;
 use crate::config::Config;
 use crate::error::Error;
+use crate::git;
+use crate::git::bisect_status::BisectStatus;
+use crate::git::conflict::{ConflictFile, ConflictSide};
 use crate::git::diff::Diff;
+use crate::git::rebase_todo::TodoLine;
 use crate::gitu_diff::Status;
+use crate::graph::{self, Graph};
 use crate::highlight;
 use crate::item_data::ItemData;
 use crate::item_data::Ref;
 use crate::item_data::SectionHeader;
 use git2::Oid;
 use git2::Repository;
+use git2::Sort;
 use ratatui::text::Line;
 use ratatui::text::Span;
 use regex::Regex;
+use std::collections::HashSet;
 use std::hash::DefaultHasher;
 use std::hash::Hash;
 use std::hash::Hasher;
@@ -45,6 +52,10 @@ impl Item {
             ItemData::AllUntracked(_) => {
                 Line::styled("Untracked files", &config.style.section_header)
             }
+            ItemData::AllUnmerged(count) => Line::from(vec![
+                Span::styled("Unmerged paths", &config.style.section_header),
+                Span::raw(format!(" ({count})")),
+            ]),
             ItemData::Reference { kind, prefix } => {
                 let (reference, style) = match kind {
                     Ref::Tag(tag) => (tag, &config.style.tag),
@@ -55,28 +66,66 @@ impl Item {
                 Line::from(vec![Span::raw(prefix), Span::styled(reference, style)])
             }
             ItemData::Commit {
+                prefix,
+                graph,
                 short_id,
                 associated_references,
                 summary,
                 ..
-            } => Line::from_iter(itertools::intersperse(
-                iter::once(Span::styled(short_id, &config.style.hash))
-                    .chain(
-                        associated_references
-                            .into_iter()
-                            .map(|reference| match reference {
-                                Ref::Tag(tag) => Span::styled(tag, &config.style.tag),
-                                Ref::Head(branch) => Span::styled(branch, &config.style.branch),
-                                Ref::Remote(remote) => Span::styled(remote, &config.style.remote),
-                            }),
-                    )
-                    .chain([Span::raw(summary)]),
-                Span::raw(" "),
-            )),
+            } => {
+                let mut spans = graph::to_spans(graph, &config.style.graph);
+                if !spans.is_empty() {
+                    spans.push(Span::raw(" "));
+                }
+
+                spans.extend(itertools::intersperse(
+                    (!prefix.is_empty())
+                        .then(|| Span::styled(format!("{prefix:6}"), &config.style.rebase_action))
+                        .into_iter()
+                        .chain([Span::styled(short_id, &config.style.hash)])
+                        .chain(
+                            associated_references
+                                .into_iter()
+                                .map(|reference| match reference {
+                                    Ref::Tag(tag) => Span::styled(tag, &config.style.tag),
+                                    Ref::Head(branch) => Span::styled(branch, &config.style.branch),
+                                    Ref::Remote(remote) => {
+                                        Span::styled(remote, &config.style.remote)
+                                    }
+                                }),
+                        )
+                        .chain([Span::raw(summary)]),
+                    Span::raw(" "),
+                ));
+
+                Line::from(spans)
+            }
             ItemData::Untracked(path) => Line::styled(
                 path.to_string_lossy().into_owned(),
                 &config.style.file_header,
             ),
+            ItemData::Unmerged { path, conflict } => Line::styled(
+                format!("{conflict:15}   {}", path.to_string_lossy()),
+                &config.style.file_header,
+            ),
+            ItemData::ConflictRegion { file, region_i } => Line::styled(
+                format!("Conflict at line {}", file.regions[region_i].line),
+                &config.style.hunk_header,
+            ),
+            ItemData::ConflictLine {
+                file,
+                line_range,
+                side,
+            } => {
+                let style = match side {
+                    ConflictSide::Marker => &config.style.hunk_header,
+                    ConflictSide::Ours => &config.style.diff_highlight.changed_old,
+                    ConflictSide::Base => &config.style.diff_highlight.unchanged_old,
+                    ConflictSide::Theirs => &config.style.diff_highlight.changed_new,
+                };
+
+                Line::styled(file.text[line_range].to_string(), style)
+            }
             ItemData::Delta { diff, file_i } => {
                 let file_diff = &diff.file_diffs[file_i];
 
@@ -96,6 +145,18 @@ impl Item {
                     }
                 );
 
+                let content = match file_diff.submodule_change(&diff.text) {
+                    Some(change) => format!(
+                        "{content}{}",
+                        submodule_summary(
+                            change.old.map(|range| &diff.text[range]),
+                            change.new.map(|range| &diff.text[range]),
+                            change.dirty
+                        )
+                    ),
+                    None => content,
+                };
+
                 Line::styled(content, &config.style.file_header)
             }
             ItemData::Hunk {
@@ -135,6 +196,91 @@ impl Item {
                 Span::styled(format!("stash@{id}"), &config.style.hash),
                 Span::raw(format!(" {message}")),
             ]),
+            ItemData::Submodule {
+                path,
+                recorded,
+                checked_out,
+                dirty,
+            } => {
+                let summary = match checked_out {
+                    Some(_) => {
+                        submodule_summary(recorded.as_deref(), checked_out.as_deref(), dirty)
+                    }
+                    None => " (uninitialized)".to_string(),
+                };
+
+                Line::from(vec![
+                    Span::styled(
+                        path.to_string_lossy().into_owned(),
+                        &config.style.file_header,
+                    ),
+                    Span::raw(summary),
+                ])
+            }
+            ItemData::Worktree {
+                display_path,
+                branch,
+                head,
+                ..
+            } => {
+                let checked_out = match (branch, head) {
+                    (Some(branch), _) => Span::styled(branch, &config.style.branch),
+                    (None, Some(head)) => {
+                        Span::styled(head.chars().take(7).collect::<String>(), &config.style.hash)
+                    }
+                    (None, None) => Span::raw("(bare)"),
+                };
+
+                Line::from(vec![checked_out, Span::raw(" "), Span::raw(display_path)])
+            }
+            ItemData::RebaseTodoLine { line, .. } => match line {
+                TodoLine::Commit {
+                    action,
+                    oid,
+                    summary,
+                } => Line::from(vec![
+                    Span::styled(format!("{action:6}"), &config.style.rebase_action),
+                    Span::raw(" "),
+                    Span::styled(oid.chars().take(7).collect::<String>(), &config.style.hash),
+                    Span::raw(" "),
+                    Span::raw(summary),
+                ]),
+                TodoLine::Exec(command) => Line::from(vec![
+                    Span::styled(format!("{:6}", "exec"), &config.style.rebase_action),
+                    Span::raw(" "),
+                    Span::raw(command),
+                ]),
+                TodoLine::Other(line) => Line::raw(line),
+            },
+            ItemData::BlameChunk { blame, chunk_i } => {
+                let chunk = &blame.chunks[chunk_i];
+                if !chunk.is_committed() {
+                    return Line::styled("Not committed yet", &config.style.hash);
+                }
+
+                Line::from(vec![
+                    Span::styled(
+                        chunk.oid.chars().take(7).collect::<String>(),
+                        &config.style.hash,
+                    ),
+                    Span::raw(" "),
+                    Span::raw(chunk.author.clone()),
+                    Span::raw(" "),
+                    Span::raw(chunk.date.clone()),
+                    Span::raw(" "),
+                    Span::raw(chunk.summary.clone()),
+                ])
+            }
+            ItemData::BlameLine { blame, line_i, .. } => {
+                let highlights = highlight::highlight_blame(blame.id, &config, &blame);
+                let line = &blame.text[blame.lines[line_i].range.clone()];
+
+                Line::from_iter(highlights.get_line_highlights(line_i).iter().map(
+                    |(highlight_range, style)| {
+                        Span::styled(line[highlight_range.clone()].replace("\t", "    "), *style)
+                    },
+                ))
+            }
             ItemData::Header(header) => {
                 let content = match header {
                     SectionHeader::Remote(remote) => format!("Remote {remote}"),
@@ -143,16 +289,38 @@ impl Item {
                     SectionHeader::NoBranch => "No branch".to_string(),
                     SectionHeader::OnBranch(branch) => format!("On branch {branch}"),
                     SectionHeader::Rebase(head, onto) => format!("Rebasing {head} onto {onto}"),
+                    SectionHeader::Split(commit, created) => {
+                        format!("Splitting {commit}, {created} commit(s) created so far")
+                    }
                     SectionHeader::Merge(head) => format!("Merging {head}"),
                     SectionHeader::Revert(head) => format!("Reverting {head}"),
                     SectionHeader::CherryPick(head) => format!("Cherry-picking {head}"),
+                    SectionHeader::Am(current, total) => {
+                        format!("Applying patches ({current}/{total})")
+                    }
+                    SectionHeader::Bisect(BisectStatus::Started) => {
+                        "Bisecting, mark a good and a bad commit to begin".to_string()
+                    }
+                    SectionHeader::Bisect(BisectStatus::Bisecting {
+                        revisions_left,
+                        steps,
+                    }) => format!(
+                        "Bisecting: {revisions_left} revision(s) left to test after this (roughly {steps} step(s))"
+                    ),
+                    SectionHeader::Bisect(BisectStatus::FoundFirstBad(commit)) => {
+                        format!("Bisecting: {commit} is the first bad commit")
+                    }
                     SectionHeader::Stashes => "Stashes".to_string(),
+                    SectionHeader::Submodules => "Submodules".to_string(),
+                    SectionHeader::Worktrees => "Worktrees".to_string(),
                     SectionHeader::RecentCommits => "Recent commits".to_string(),
                     SectionHeader::Commit(oid) => format!("commit {oid}"),
+                    SectionHeader::Diff(title) => title,
                     SectionHeader::StashRef(stash_ref) => stash_ref,
                     SectionHeader::StagedChanges(count) => format!("Staged changes ({count})"),
                     SectionHeader::UnstagedChanges(count) => format!("Unstaged changes ({count})"),
                     SectionHeader::UntrackedFiles(count) => format!("Untracked files ({count})"),
+                    SectionHeader::Ignore(file) => format!("Add to {file}"),
                 };
 
                 Line::styled(content, &config.style.section_header)
@@ -177,6 +345,25 @@ impl Item {
     }
 }
 
+/// Summarizes a submodule change, e.g. " 25e1f6e..8858c96 (modified content)".
+/// A single commit is shown if the submodule didn't move.
+pub(crate) fn submodule_summary(old: Option<&str>, new: Option<&str>, dirty: bool) -> String {
+    let short = |hash: &str| hash.chars().take(7).collect::<String>();
+
+    let commits = match (old, new) {
+        (Some(old), Some(new)) if old == new => format!(" {}", short(new)),
+        (Some(old), Some(new)) => format!(" {}..{}", short(old), short(new)),
+        (Some(hash), None) | (None, Some(hash)) => format!(" {}", short(hash)),
+        (None, None) => String::new(),
+    };
+
+    if dirty {
+        format!("{commits} (modified content)")
+    } else {
+        commits
+    }
+}
+
 pub(crate) fn create_diff_items(
     diff: &Rc<Diff>,
     depth: usize,
@@ -186,6 +373,12 @@ pub(crate) fn create_diff_items(
         .iter()
         .enumerate()
         .flat_map(move |(file_i, file_diff)| {
+            // A submodule's `Subproject commit` hunk is summarized on the delta itself
+            let hunk_count = match file_diff.submodule_change(&diff.text) {
+                Some(_) => 0,
+                None => file_diff.hunks.len(),
+            };
+
             iter::once(Item {
                 id: hash(diff.file_diff_header(file_i)),
                 default_collapsed,
@@ -196,11 +389,9 @@ pub(crate) fn create_diff_items(
                 },
                 ..Default::default()
             })
-            .chain(file_diff.hunks.iter().cloned().enumerate().flat_map(
-                move |(hunk_i, _hunk)| {
-                    create_hunk_items(Rc::clone(diff), file_i, hunk_i, depth + 1)
-                },
-            ))
+            .chain((0..hunk_count).flat_map(move |hunk_i| {
+                create_hunk_items(Rc::clone(diff), file_i, hunk_i, depth + 1)
+            }))
         })
 }
 
@@ -260,6 +451,41 @@ fn format_diff_hunk_items(
         .collect()
 }
 
+/// The conflict regions of a file, each followed by its lines.
+pub(crate) fn create_conflict_items(
+    file: &Rc<ConflictFile>,
+    depth: usize,
+) -> impl Iterator<Item = Item> + '_ {
+    (0..file.regions.len()).flat_map(move |region_i| {
+        let region_hash = hash([&file.path, &file.text[file.regions[region_i].range.clone()]]);
+
+        iter::once(Item {
+            id: region_hash,
+            depth,
+            data: ItemData::ConflictRegion {
+                file: Rc::clone(file),
+                region_i,
+            },
+            ..Default::default()
+        })
+        .chain(
+            file.region_lines(region_i)
+                .into_iter()
+                .map(move |(line_range, side)| Item {
+                    id: region_hash,
+                    depth: depth + 1,
+                    unselectable: true,
+                    data: ItemData::ConflictLine {
+                        file: Rc::clone(file),
+                        line_range,
+                        side,
+                    },
+                    ..Default::default()
+                }),
+        )
+    })
+}
+
 pub(crate) fn stash_list(repo: &Repository, limit: usize) -> Res<Vec<Item>> {
     Ok(repo
         .reflog("refs/stash")
@@ -295,61 +521,108 @@ pub(crate) fn stash_list(repo: &Repository, limit: usize) -> Res<Vec<Item>> {
         .collect::<Vec<_>>())
 }
 
+/// Narrows down the commits listed by [`log`].
+#[derive(Clone, Default)]
+pub(crate) struct LogFilter {
+    pub msg_regex: Option<Regex>,
+    pub first_parent: bool,
+    pub no_merges: bool,
+    /// Passed on to `git log`, for filters like `--author` or `-S` libgit2 can't apply
+    pub git_args: Vec<String>,
+    pub paths: Vec<String>,
+}
+
 pub(crate) fn log(
     repo: &Repository,
     limit: usize,
     rev: Option<Oid>,
-    msg_regex: Option<Regex>,
+    filter: &LogFilter,
 ) -> Res<Vec<Item>> {
     let mut revwalk = repo.revwalk().map_err(Error::ReadLog)?;
+    revwalk
+        .set_sorting(Sort::TOPOLOGICAL | Sort::TIME)
+        .map_err(Error::ReadLog)?;
+    if filter.first_parent {
+        revwalk.simplify_first_parent().map_err(Error::ReadLog)?;
+    }
     if let Some(r) = rev {
         revwalk.push(r).map_err(Error::ReadLog)?;
     } else if revwalk.push_head().is_err() {
         return Ok(vec![]);
     }
 
-    let references: Vec<_> = repo
-        .references()
-        .map_err(Error::ReadLog)?
-        .filter_map(Result::ok)
-        .filter_map(
-            |reference| match (reference.peel_to_commit(), reference.shorthand()) {
-                (Ok(target), Some(name)) => {
-                    if name.ends_with("/HEAD") || name.starts_with("prefetch/remotes/") {
-                        return None;
-                    }
+    let matching = if filter.git_args.is_empty() && filter.paths.is_empty() {
+        None
+    } else {
+        let mut args = filter.git_args.clone();
+        if filter.first_parent {
+            args.push("--first-parent".into());
+        }
 
-                    let name = name.to_owned();
+        Some(git::log_oids(repo, rev, &args, &filter.paths)?)
+    };
 
-                    let ref_kind = if reference.is_remote() {
-                        Ref::Remote(name)
-                    } else if reference.is_tag() {
-                        Ref::Tag(name)
-                    } else {
-                        Ref::Head(name)
-                    };
+    log_items(repo, revwalk, limit, filter, matching.as_ref())
+}
 
-                    Some((target, ref_kind))
-                }
-                _ => None,
-            },
-        )
-        .collect();
+/// Lists the commits reachable from `to` but not from `from`, like `git log from..to`.
+pub(crate) fn log_range(repo: &Repository, from: Option<Oid>, to: Oid) -> Res<Vec<Item>> {
+    let mut revwalk = repo.revwalk().map_err(Error::ReadLog)?;
+    revwalk
+        .set_sorting(Sort::TOPOLOGICAL | Sort::TIME)
+        .map_err(Error::ReadLog)?;
+    revwalk.push(to).map_err(Error::ReadLog)?;
+    if let Some(from) = from {
+        revwalk.hide(from).map_err(Error::ReadLog)?;
+    }
+
+    log_items(repo, revwalk, usize::MAX, &LogFilter::default(), None)
+}
+
+fn log_items(
+    repo: &Repository,
+    revwalk: git2::Revwalk,
+    limit: usize,
+    filter: &LogFilter,
+    matching: Option<&HashSet<Oid>>,
+) -> Res<Vec<Item>> {
+    let references = commit_references(repo)?;
+
+    let mut graph = Graph::default();
+    // Hidden commits would leave gaps in the lanes, so filtered logs have no graph
+    let draw_graph = filter.msg_regex.is_none() && !filter.no_merges && matching.is_none();
 
     let items: Vec<Item> = revwalk
         .map(|oid_result| -> Res<Option<Item>> {
             let oid = oid_result.map_err(Error::ReadLog)?;
             let commit = repo.find_commit(oid).map_err(Error::ReadLog)?;
+            let parents = commit.parent_ids().collect::<Vec<_>>();
 
             let short_id = commit.as_object().short_id().map_err(Error::ReadOid)?;
             let short_id = String::from_utf8_lossy(&short_id).to_string();
 
-            if let Some(re) = &msg_regex
+            if let Some(re) = &filter.msg_regex
                 && !re.is_match(commit.message().unwrap_or(""))
             {
                 return Ok(None);
             }
 
+            if filter.no_merges && parents.len() > 1 {
+                return Ok(None);
+            }
+
+            if matching.is_some_and(|matching| !matching.contains(&oid)) {
+                return Ok(None);
+            }
+
+            let graph_row = if !draw_graph {
+                vec![]
+            } else if filter.first_parent {
+                graph.next_row(oid, &parents[..parents.len().min(1)])
+            } else {
+                graph.next_row(oid, &parents)
+            };
+
             let associated_references: Vec<_> = references
                 .iter()
                 .filter(|(commit, _)| commit.id() == oid)
@@ -357,10 +630,13 @@ pub(crate) fn log(
                 .collect();
 
             let data = ItemData::Commit {
+                prefix: "",
+                graph: graph_row,
                 oid: oid.to_string(),
                 short_id,
                 associated_references,
                 summary: commit.summary().unwrap_or("").to_string(),
+                collapsible: false,
             };
 
             Ok(Some(Item {
@@ -394,6 +670,145 @@ pub(crate) fn log(
     }
 }
 
+/// Lists the entries of `refname`'s reflog, newest first, like `git reflog show`.
+pub(crate) fn reflog(repo: &Repository, refname: &str) -> Res<Vec<Item>> {
+    let shorthand = refname.strip_prefix("refs/heads/").unwrap_or(refname);
+
+    let items: Vec<Item> = repo
+        .reflog(refname)
+        .map_err(Error::ReadReflog)?
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            let oid = entry.id_new();
+            let short_id = repo
+                .find_object(oid, None)
+                .and_then(|object| object.short_id())
+                .map(|short_id| String::from_utf8_lossy(&short_id).to_string())
+                .unwrap_or_else(|_| oid.to_string()[..7].to_string());
+
+            Item {
+                id: hash((i, oid)),
+                depth: 1,
+                data: ItemData::Commit {
+                    prefix: "",
+                    graph: vec![],
+                    oid: oid.to_string(),
+                    short_id,
+                    associated_references: vec![],
+                    summary: format!(
+                        "{shorthand}@{{{i}}}: {}",
+                        entry.message().unwrap_or("").trim_end()
+                    ),
+                    collapsible: false,
+                },
+                ..Default::default()
+            }
+        })
+        .collect();
+
+    if items.is_empty() {
+        Ok(vec![Item {
+            data: ItemData::Raw("No reflog entries found".to_string()),
+            ..Default::default()
+        }])
+    } else {
+        Ok(items)
+    }
+}
+
+/// Every commit a branch, tag or remote branch points at, with the reference.
+fn commit_references(repo: &Repository) -> Res<Vec<(git2::Commit<'_>, Ref)>> {
+    Ok(repo
+        .references()
+        .map_err(Error::ReadLog)?
+        .filter_map(Result::ok)
+        .filter_map(
+            |reference| match (reference.peel_to_commit(), reference.shorthand()) {
+                (Ok(target), Some(name)) => {
+                    if name.ends_with("/HEAD") || name.starts_with("prefetch/remotes/") {
+                        return None;
+                    }
+
+                    let name = name.to_owned();
+
+                    let ref_kind = if reference.is_remote() {
+                        Ref::Remote(name)
+                    } else if reference.is_tag() {
+                        Ref::Tag(name)
+                    } else {
+                        Ref::Head(name)
+                    };
+
+                    Some((target, ref_kind))
+                }
+                _ => None,
+            },
+        )
+        .collect())
+}
+
+/// Lists the commits that touched `path`, following renames, each expanding to the file's diff.
+pub(crate) fn file_log(repo: &Repository, path: &str) -> Res<Vec<Item>> {
+    let references = commit_references(repo)?;
+
+    let items: Vec<Item> = git::file_log(repo, path)?
+        .into_iter()
+        .map(|diff| -> Res<Vec<Item>> {
+            let oid = diff.new_rev.clone().unwrap_or_default();
+            let commit = repo
+                .find_commit(Oid::from_str(&oid).map_err(Error::ReadLog)?)
+                .map_err(Error::ReadLog)?;
+
+            let short_id = commit.as_object().short_id().map_err(Error::ReadOid)?;
+            let associated_references = references
+                .iter()
+                .filter(|(target, _)| target.id() == commit.id())
+                .map(|(_, reference)| reference.clone())
+                .collect();
+
+            let diff = Rc::new(diff);
+            Ok(iter::once(Item {
+                id: hash(&oid),
+                default_collapsed: true,
+                depth: 1,
+                data: ItemData::Commit {
+                    prefix: "",
+                    graph: vec![],
+                    short_id: String::from_utf8_lossy(&short_id).to_string(),
+                    associated_references,
+                    summary: commit.summary().unwrap_or("").to_string(),
+                    oid,
+                    collapsible: true,
+                },
+                ..Default::default()
+            })
+            .chain(create_diff_items(&diff, 2, false))
+            .collect())
+        })
+        .flat_map(|result| match result {
+            Ok(items) => items,
+            Err(err) => {
+                let err = err.to_string();
+                vec![Item {
+                    id: hash(&err),
+                    data: ItemData::Error(err),
+                    ..Default::default()
+                }]
+            }
+        })
+        .collect();
+
+    if items.is_empty() {
+        Ok(vec![Item {
+            data: ItemData::Raw("No commits found".to_string()),
+            ..Default::default()
+        }])
+    } else {
+        Ok(items)
+    }
+}
+
 pub(crate) fn blank_line() -> Item {
     Item {
         depth: 0,
@@ -16,18 +16,33 @@ pub(crate) enum Menu {
     #[serde(rename = "root")]
     #[strum(serialize = "root")]
     Root,
+    #[serde(rename = "bisect_menu")]
+    #[strum(serialize = "bisect_menu")]
+    Bisect,
     #[serde(rename = "branch_menu")]
     #[strum(serialize = "branch_menu")]
     Branch,
+    #[serde(rename = "branch_config_menu")]
+    #[strum(serialize = "branch_config_menu")]
+    BranchConfig,
+    #[serde(rename = "clean_menu")]
+    #[strum(serialize = "clean_menu")]
+    Clean,
     #[serde(rename = "commit_menu")]
     #[strum(serialize = "commit_menu")]
     Commit,
+    #[serde(rename = "diff_menu")]
+    #[strum(serialize = "diff_menu")]
+    Diff,
     #[serde(rename = "fetch_menu")]
     #[strum(serialize = "fetch_menu")]
     Fetch,
     #[serde(rename = "help_menu")]
     #[strum(serialize = "help_menu")]
     Help,
+    #[serde(rename = "ignore_menu")]
+    #[strum(serialize = "ignore_menu")]
+    Ignore,
     #[serde(rename = "log_menu")]
     #[strum(serialize = "log_menu")]
     Log,
@@ -36,6 +51,9 @@ pub(crate) enum Menu {
     #[serde(rename = "remote_menu")]
     #[strum(serialize = "remote_menu")]
     Remote,
+    #[serde(rename = "patch_menu")]
+    #[strum(serialize = "patch_menu")]
+    Patch,
     #[serde(rename = "pull_menu")]
     #[strum(serialize = "pull_menu")]
     Pull,
@@ -45,6 +63,9 @@ pub(crate) enum Menu {
     #[serde(rename = "rebase_menu")]
     #[strum(serialize = "rebase_menu")]
     Rebase,
+    #[serde(rename = "rebase_todo_menu")]
+    #[strum(serialize = "rebase_todo_menu")]
+    RebaseTodo,
     #[serde(rename = "reset_menu")]
     #[strum(serialize = "reset_menu")]
     Reset,
@@ -57,6 +78,15 @@ pub(crate) enum Menu {
     #[serde(rename = "stash_menu")]
     #[strum(serialize = "stash_menu")]
     Stash,
+    #[serde(rename = "submodule_menu")]
+    #[strum(serialize = "submodule_menu")]
+    Submodule,
+    #[serde(rename = "tag_menu")]
+    #[strum(serialize = "tag_menu")]
+    Tag,
+    #[serde(rename = "worktree_menu")]
+    #[strum(serialize = "worktree_menu")]
+    Worktree,
 }
 
 pub(crate) struct PendingMenu {
@@ -72,20 +102,30 @@ impl PendingMenu {
             is_hidden: false,
             args: match menu {
                 Menu::Root => vec![],
+                Menu::Bisect => ops::bisect::init_args(),
                 Menu::Branch => ops::branch::init_args(),
+                Menu::BranchConfig => vec![],
+                Menu::Clean => ops::clean::init_args(),
                 Menu::Commit => ops::commit::init_args(),
+                Menu::Diff => ops::diff::init_args(),
                 Menu::Fetch => ops::fetch::init_args(),
                 Menu::Help => vec![],
+                Menu::Ignore => vec![],
                 Menu::Log => ops::log::init_args(),
                 Menu::Merge => ops::merge::init_args(),
+                Menu::Patch => ops::patch::init_args(),
                 Menu::Pull => ops::pull::init_args(),
                 Menu::Push => ops::push::init_args(),
                 Menu::Rebase => ops::rebase::init_args(),
+                Menu::RebaseTodo => vec![],
                 Menu::Remote => vec![],
                 Menu::Reset => ops::reset::init_args(),
                 Menu::Revert => ops::revert::init_args(),
                 Menu::CherryPick => ops::cherry_pick::init_args(),
                 Menu::Stash => ops::stash::init_args(),
+                Menu::Submodule => ops::submodule::init_args(),
+                Menu::Tag => ops::tag::init_args(),
+                Menu::Worktree => ops::worktree::init_args(),
             }
             .into_iter()
             .map(|arg| (Cow::from(arg.arg), arg))
@@ -176,6 +176,39 @@ pub fn any_regex(s: &str) -> Res<Regex> {
     Regex::try_from(s).map_err(Error::ArgInvalidRegex)
 }
 
+pub fn any_string(s: &str) -> Res<String> {
+    Ok(s.to_string())
+}
+
+/// Splits a value into words like a shell would, so `"a file" b\ c` gives `a file` and `b c`.
+pub fn split_words(s: &str) -> Vec<String> {
+    let mut words = vec![];
+    let mut word: Option<String> = None;
+    let mut quote = None;
+    let mut chars = s.chars();
+
+    while let Some(c) = chars.next() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some('"'), '\\') | (None, '\\') => {
+                if let Some(next) = chars.next() {
+                    word.get_or_insert_default().push(next);
+                }
+            }
+            (Some(_), c) => word.get_or_insert_default().push(c),
+            (None, '"' | '\'') => {
+                quote = Some(c);
+                word.get_or_insert_default();
+            }
+            (None, c) if c.is_whitespace() => words.extend(word.take()),
+            (None, c) => word.get_or_insert_default().push(c),
+        }
+    }
+
+    words.extend(word);
+    words
+}
+
 #[cfg(test)]
 mod tests {
     use crate::menu::arg::{self, Arg};
@@ -228,4 +261,15 @@ mod tests {
         assert_eq!(arg.value_as::<String>(), None);
         assert_eq!(arg.value_as::<u32>(), Some(&1u32));
     }
+
+    #[test]
+    fn split_words() {
+        assert_eq!(arg::split_words("a  b"), vec!["a", "b"]);
+        assert_eq!(
+            arg::split_words(r#""a file" 'it''s' b\ c"#),
+            vec!["a file", "its", "b c"]
+        );
+        assert_eq!(arg::split_words(r#""" x"#), vec!["", "x"]);
+        assert!(arg::split_words("  ").is_empty());
+    }
 }
@@ -52,7 +52,7 @@ fn apply_stash(stash_ref: String) -> Action {
 
 fn apply_line(diff: &Rc<Diff>, file_i: usize, hunk_i: usize, line_i: usize) -> Action {
     let patch = diff
-        .format_line_patch(file_i, hunk_i, line_i..(line_i + 1), PatchMode::Normal)
+        .format_line_patch(file_i, hunk_i, &[line_i], PatchMode::Normal)
         .into_bytes();
 
     Rc::new(move |app: &mut App, term: &mut Term| {
@@ -0,0 +1,131 @@
+use super::{Action, OpTrait, pick_rev};
+use crate::{
+    app::{App, PromptParams, State},
+    error::Error,
+    git,
+    item_data::{ItemData, Rev},
+    menu::arg::Arg,
+    term::Term,
+};
+use std::{process::Command, rc::Rc};
+
+pub(crate) fn init_args() -> Vec<Arg> {
+    vec![]
+}
+
+pub(crate) struct BisectStart;
+impl OpTrait for BisectStart {
+    fn get_action(&self, target: &ItemData) -> Option<Action> {
+        let selected = target.rev();
+
+        Some(Rc::new(move |app: &mut App, term: &mut Term| {
+            let bad_default = match selected.clone() {
+                Some(rev) => rev,
+                None => git::head(&app.state.repo)?,
+            };
+
+            let Some(bad) = pick_rev(app, term, "Start bisect with bad revision", bad_default)?
+            else {
+                return Ok(());
+            };
+
+            let good_default = Rev::Commit(format!("{bad}~"));
+            let Some(good) = pick_rev(app, term, "Good revision", good_default)? else {
+                return Ok(());
+            };
+
+            let mut cmd = Command::new("git");
+            cmd.args(["bisect", "start", &bad, &good]);
+            app.run_cmd(term, &[], cmd)
+        }))
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "Start".into()
+    }
+}
+
+pub(crate) struct BisectGood;
+impl OpTrait for BisectGood {
+    fn get_action(&self, target: &ItemData) -> Option<Action> {
+        bisect_mark("good", target.rev())
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "Mark good".into()
+    }
+}
+
+pub(crate) struct BisectBad;
+impl OpTrait for BisectBad {
+    fn get_action(&self, target: &ItemData) -> Option<Action> {
+        bisect_mark("bad", target.rev())
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "Mark bad".into()
+    }
+}
+
+pub(crate) struct BisectSkip;
+impl OpTrait for BisectSkip {
+    fn get_action(&self, target: &ItemData) -> Option<Action> {
+        bisect_mark("skip", target.rev())
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "Skip".into()
+    }
+}
+
+/// Marks the selected commit, or the currently checked out one if there is none.
+fn bisect_mark(term_name: &'static str, rev: Option<Rev>) -> Option<Action> {
+    Some(Rc::new(move |app: &mut App, term: &mut Term| {
+        let mut cmd = Command::new("git");
+        cmd.args(["bisect", term_name]);
+        cmd.args(rev.iter().map(Rev::shorthand));
+        app.run_cmd(term, &[], cmd)
+    }))
+}
+
+pub(crate) struct BisectReset;
+impl OpTrait for BisectReset {
+    fn get_action(&self, _target: &ItemData) -> Option<Action> {
+        Some(Rc::new(|app: &mut App, term: &mut Term| {
+            let mut cmd = Command::new("git");
+            cmd.args(["bisect", "reset"]);
+            app.run_cmd(term, &[], cmd)
+        }))
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "Reset".into()
+    }
+}
+
+pub(crate) struct BisectRun;
+impl OpTrait for BisectRun {
+    fn get_action(&self, _target: &ItemData) -> Option<Action> {
+        Some(Rc::new(|app: &mut App, term: &mut Term| {
+            let script = app.prompt(
+                term,
+                &PromptParams {
+                    prompt: "Bisect run script",
+                    ..Default::default()
+                },
+            )?;
+
+            if script.is_empty() {
+                return Err(Error::BisectScriptRequired);
+            }
+
+            let mut cmd = Command::new("git");
+            cmd.args(["bisect", "run", "sh", "-c", &script]);
+            app.run_cmd_async(term, &[], cmd)
+        }))
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "Run script".into()
+    }
+}
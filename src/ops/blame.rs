@@ -0,0 +1,134 @@
+use super::{Action, OpTrait};
+use crate::{
+    Res,
+    app::{App, State},
+    error::Error,
+    item_data::ItemData,
+    screen,
+    term::Term,
+};
+use std::{rc::Rc, sync::Arc};
+
+pub(crate) struct Blame;
+impl OpTrait for Blame {
+    fn get_action(&self, target: &ItemData) -> Option<Action> {
+        let (diff, file_i, line) = match target {
+            ItemData::Delta { diff, file_i } => (diff, *file_i, None),
+            ItemData::Hunk {
+                diff,
+                file_i,
+                hunk_i,
+            } => (
+                diff,
+                *file_i,
+                Some(diff.file_line_of_first_diff(*file_i, *hunk_i)),
+            ),
+            ItemData::HunkLine {
+                diff,
+                file_i,
+                hunk_i,
+                line_i,
+                ..
+            } => {
+                let hunk = &diff.file_diffs[*file_i].hunks[*hunk_i];
+                let preceding_new_lines = diff
+                    .hunk_content(*file_i, *hunk_i)
+                    .lines()
+                    .take(*line_i)
+                    .filter(|line| !line.starts_with('-'))
+                    .count();
+
+                (
+                    diff,
+                    *file_i,
+                    Some(hunk.header.new_line_start as usize + preceding_new_lines),
+                )
+            }
+            _ => return None,
+        };
+
+        let file_diff = &diff.file_diffs[file_i];
+        if file_diff.submodule_change(&diff.text).is_some() {
+            return None;
+        }
+
+        let path = file_diff.header.new_file.fmt(&diff.text).to_string();
+        let rev = diff.new_rev.clone();
+
+        Some(Rc::new(move |app: &mut App, term: &mut Term| {
+            goto_blame_screen(app, term, path.clone(), rev.clone(), line)
+        }))
+    }
+
+    fn is_target_op(&self) -> bool {
+        true
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "Blame".into()
+    }
+}
+
+/// Blames the file as it was before the commit that last touched the selected line.
+pub(crate) struct BlameParent;
+impl OpTrait for BlameParent {
+    fn get_action(&self, target: &ItemData) -> Option<Action> {
+        let (blame, chunk_i, line_i) = match target {
+            ItemData::BlameChunk { blame, chunk_i } => {
+                (blame, *chunk_i, blame.chunks[*chunk_i].lines.start)
+            }
+            ItemData::BlameLine {
+                blame,
+                chunk_i,
+                line_i,
+            } => (blame, *chunk_i, *line_i),
+            _ => return None,
+        };
+
+        let previous = blame.chunks[chunk_i].previous.clone();
+        let line = blame.lines[line_i].orig_line;
+
+        Some(Rc::new(move |app: &mut App, term: &mut Term| {
+            let Some((rev, path)) = previous.clone() else {
+                return Err(Error::NoBlameParent);
+            };
+
+            goto_blame_screen(app, term, path, Some(rev), Some(line))
+        }))
+    }
+
+    fn is_target_op(&self) -> bool {
+        true
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "Blame parent".into()
+    }
+}
+
+/// Pushes a blame screen, selecting the given (1-based) line of the file if any.
+pub(crate) fn goto_blame_screen(
+    app: &mut App,
+    term: &mut Term,
+    path: String,
+    rev: Option<String>,
+    line: Option<usize>,
+) -> Res<()> {
+    let mut screen = screen::blame::create(
+        Arc::clone(&app.state.config),
+        Rc::clone(&app.state.repo),
+        term.size().map_err(Error::Term)?,
+        path,
+        rev,
+    )?;
+
+    if let Some(line) = line {
+        let line_i = line.saturating_sub(1);
+        screen.select_item(
+            |data| matches!(data, ItemData::BlameLine { line_i: i, .. } if *i >= line_i),
+        );
+    }
+
+    app.state.screens.push(screen);
+    Ok(())
+}
@@ -1,13 +1,13 @@
-use super::{Action, OpTrait};
+use super::{Action, OpTrait, pick_rev};
 use crate::{
     Res,
     app::{App, PromptParams, State},
     error::Error,
     git::{
         self, does_branch_exist, get_current_branch, get_current_branch_name, is_branch_merged,
-        remote::get_branch_upstream,
+        remote::{get_branch_upstream, split_remote_branch},
     },
-    item_data::{ItemData, Ref},
+    item_data::{ItemData, Ref, Rev},
     menu::arg::Arg,
     picker::{PickerParams, PickerState},
     term::Term,
@@ -47,7 +47,41 @@ impl OpTrait for Checkout {
     }
 }
 
+pub(crate) struct CheckoutLocalBranch;
+impl OpTrait for CheckoutLocalBranch {
+    fn get_action(&self, _target: &ItemData) -> Option<Action> {
+        Some(Rc::new(move |app: &mut App, term: &mut Term| {
+            let result = app.pick(
+                term,
+                PickerState::with_branches_to_checkout(PickerParams {
+                    prompt: "Checkout local branch".into(),
+                    refs: &git::branches(&app.state.repo, None)?,
+                    exclude_ref: git::head_ref(&app.state.repo)?,
+                    default: app.selected_rev(),
+                    allow_custom_input: false,
+                }),
+            )?;
+
+            if let Some(data) = result {
+                checkout(app, term, data.display())?;
+            }
+
+            Ok(())
+        }))
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "Checkout local branch".into()
+    }
+}
+
 fn checkout(app: &mut App, term: &mut Term, rev: &str) -> Res<()> {
+    if let Some((_remote, branch)) = split_remote_branch(&app.state.repo, rev)?
+        && !does_branch_exist(&app.state.repo, rev)?
+    {
+        return checkout_tracking(app, term, rev, &branch);
+    }
+
     let mut cmd = Command::new("git");
     cmd.args(["checkout", rev]);
 
@@ -55,6 +89,41 @@ fn checkout(app: &mut App, term: &mut Term, rev: &str) -> Res<()> {
     Ok(())
 }
 
+/// Checks out a local branch tracking a remote branch, reusing one that already tracks it.
+fn checkout_tracking(app: &mut App, term: &mut Term, remote_branch: &str, branch: &str) -> Res<()> {
+    let already_tracking = app
+        .state
+        .repo
+        .find_branch(branch, git2::BranchType::Local)
+        .ok()
+        .and_then(|local| get_branch_upstream(&local).ok().flatten())
+        .is_some_and(|upstream| upstream.get().shorthand() == Some(remote_branch));
+
+    let mut cmd = Command::new("git");
+
+    if already_tracking {
+        cmd.args(["checkout", branch]);
+    } else {
+        let default = branch.to_string();
+        let branch_name = app.prompt(
+            term,
+            &PromptParams {
+                prompt: "Checkout as local branch",
+                create_default_value: Box::new(move |_| Some(default.clone())),
+                ..Default::default()
+            },
+        )?;
+
+        if branch_name.is_empty() {
+            return Err(Error::BranchNameRequired);
+        }
+
+        cmd.args(["checkout", "-b", &branch_name, "--track", remote_branch]);
+    }
+
+    app.run_cmd(term, &[], cmd)
+}
+
 pub(crate) struct CheckoutNewBranch;
 impl OpTrait for CheckoutNewBranch {
     fn get_action(&self, _target: &ItemData) -> Option<Action> {
@@ -84,6 +153,103 @@ fn checkout_new_branch_prompt_update(app: &mut App, term: &mut Term, branch_name
     Ok(())
 }
 
+pub(crate) struct CreateBranch;
+impl OpTrait for CreateBranch {
+    fn get_action(&self, target: &ItemData) -> Option<Action> {
+        let default = target.rev();
+
+        Some(Rc::new(move |app: &mut App, term: &mut Term| {
+            let default = match default.clone() {
+                Some(rev) => rev,
+                None => git::head(&app.state.repo)?,
+            };
+
+            let Some(start) = pick_rev(app, term, "Create branch starting at", default)? else {
+                return Ok(());
+            };
+
+            let branch_name = app.prompt(
+                term,
+                &PromptParams {
+                    prompt: "Name for new branch",
+                    ..Default::default()
+                },
+            )?;
+
+            if branch_name.is_empty() {
+                return Err(Error::BranchNameRequired);
+            }
+
+            let mut cmd = Command::new("git");
+            cmd.args(["branch", &branch_name, &start]);
+            app.run_cmd(term, &[], cmd)
+        }))
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "Create branch".into()
+    }
+}
+
+pub(crate) struct ResetBranch;
+impl OpTrait for ResetBranch {
+    fn get_action(&self, target: &ItemData) -> Option<Action> {
+        let target_rev = target.rev();
+
+        Some(Rc::new(move |app: &mut App, term: &mut Term| {
+            // A selected branch is the one to reset, anything else is where to reset it to
+            let (branch_default, rev_default) = match &target_rev {
+                Some(Rev::Ref(Ref::Head(branch))) => {
+                    (Some(Rev::Ref(Ref::Head(branch.clone()))), None)
+                }
+                rev => (git::head_ref(&app.state.repo)?.map(Rev::Ref), rev.clone()),
+            };
+
+            let result = app.pick(
+                term,
+                PickerState::with_branches(PickerParams {
+                    prompt: "Reset branch".into(),
+                    refs: &git::branches(&app.state.repo, None)?,
+                    exclude_ref: None,
+                    default: branch_default,
+                    allow_custom_input: false,
+                }),
+            )?;
+
+            let Some(data) = result else {
+                return Ok(());
+            };
+            let branch_name = data.display().to_string();
+
+            let rev_default = match rev_default {
+                Some(rev) => rev,
+                None => git::head(&app.state.repo)?,
+            };
+
+            let Some(rev) = pick_rev(app, term, "Reset branch to", rev_default)? else {
+                return Ok(());
+            };
+
+            let mut cmd = Command::new("git");
+            if get_current_branch_name(&app.state.repo).ok().as_ref() == Some(&branch_name) {
+                app.confirm(
+                    term,
+                    "Uncommitted changes will be lost. Really reset? (y or n)",
+                )?;
+                cmd.args(["reset", "--hard", &rev]);
+            } else {
+                cmd.args(["branch", "--force", &branch_name, &rev]);
+            }
+
+            app.run_cmd(term, &[], cmd)
+        }))
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "Reset branch".into()
+    }
+}
+
 pub(crate) struct Delete;
 impl OpTrait for Delete {
     fn get_action(&self, target: &ItemData) -> Option<Action> {
@@ -110,6 +276,27 @@ impl OpTrait for Delete {
         }))
     }
 
+    fn get_marked_action(&self, targets: &[&ItemData]) -> Option<Action> {
+        let branches = targets
+            .iter()
+            .map(|target| match target {
+                ItemData::Reference {
+                    kind: Ref::Head(branch),
+                    ..
+                } => Some(branch.clone()),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(Rc::new(move |app: &mut App, term: &mut Term| {
+            delete_several(app, term, &branches)
+        }))
+    }
+
+    fn acts_on_marks(&self) -> bool {
+        true
+    }
+
     fn display(&self, _state: &State) -> String {
         "Delete branch".into()
     }
@@ -120,6 +307,12 @@ pub fn delete(app: &mut App, term: &mut Term, branch_name: &str) -> Res<()> {
         return Err(Error::BranchNameRequired);
     }
 
+    if !does_branch_exist(&app.state.repo, branch_name)?
+        && let Some((remote, branch)) = split_remote_branch(&app.state.repo, branch_name)?
+    {
+        return delete_remote(app, term, &remote, &branch);
+    }
+
     if get_current_branch_name(&app.state.repo).unwrap() == branch_name {
         return Err(Error::CannotDeleteCurrentBranch);
     }
@@ -138,6 +331,62 @@ pub fn delete(app: &mut App, term: &mut Term, branch_name: &str) -> Res<()> {
     Ok(())
 }
 
+fn delete_remote(app: &mut App, term: &mut Term, remote: &str, branch: &str) -> Res<()> {
+    app.confirm(term, "Really delete the branch on the remote? (y or n)")?;
+
+    let mut cmd = Command::new("git");
+    cmd.args(["push", remote, "--delete", branch]);
+    app.run_cmd(term, &[], cmd)
+}
+
+/// Deletes the merged branches right away, and the unmerged ones only once confirmed.
+fn delete_several(app: &mut App, term: &mut Term, branches: &[String]) -> Res<()> {
+    let current = get_current_branch_name(&app.state.repo).ok();
+    if branches
+        .iter()
+        .any(|branch| current.as_ref() == Some(branch))
+    {
+        return Err(Error::CannotDeleteCurrentBranch);
+    }
+
+    let (merged, unmerged): (Vec<_>, Vec<_>) = branches
+        .iter()
+        .partition(|branch| is_branch_merged(&app.state.repo, branch).unwrap_or(false));
+
+    if !merged.is_empty() {
+        let mut cmd = Command::new("git");
+        cmd.args(["branch", "-d"]);
+        cmd.args(&merged);
+        app.run_cmd(term, &[], cmd)?;
+    }
+
+    if unmerged.is_empty() {
+        return Ok(());
+    }
+
+    match app.confirm(
+        term,
+        "Some branches are not fully merged. Really delete them? (y or n)",
+    ) {
+        Ok(()) => {
+            let mut cmd = Command::new("git");
+            cmd.args(["branch", "-d", "-f"]);
+            cmd.args(&unmerged);
+            app.run_cmd(term, &[], cmd)
+        }
+        Err(Error::PromptAborted) => {
+            let kept = unmerged
+                .iter()
+                .map(|branch| branch.as_str())
+                .collect::<Vec<_>>()
+                .join(", ");
+            app.display_info(format!("Kept unmerged branches: {kept}"));
+            Ok(())
+        }
+        Err(e) => Err(e),
+    }
+}
+
 pub(crate) struct Rename;
 impl OpTrait for Rename {
     fn get_action(&self, target: &ItemData) -> Option<Action> {
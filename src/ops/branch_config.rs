@@ -0,0 +1,169 @@
+use super::{Action, OpTrait};
+use crate::{
+    Res,
+    app::{App, State},
+    git::{self, get_branch_config, get_current_branch_name, remote::remote_names},
+    item_data::ItemData,
+    picker::{PickerParams, PickerState},
+    term::Term,
+};
+use std::{process::Command, rc::Rc};
+
+/// Shows `branch.<name>.<key>` of the current branch along with its value.
+/// Detached, there's no branch to configure and only the key is shown.
+fn describe(state: &State, key: &str) -> String {
+    let Ok(branch) = get_current_branch_name(&state.repo) else {
+        return format!("branch.<none>.{key}");
+    };
+
+    match get_branch_config(&state.repo, key).ok().flatten() {
+        Some(value) => format!(
+            "branch.{branch}.{key} {}",
+            value.lines().next().unwrap_or_default()
+        ),
+        None => format!("branch.{branch}.{key} unset"),
+    }
+}
+
+/// Sets or unsets `branch.<name>.<key>` of the current branch, keeping the menu open to show it.
+fn set_branch_config(app: &mut App, term: &mut Term, key: &str, value: Option<&str>) -> Res<()> {
+    let branch = get_current_branch_name(&app.state.repo)?;
+    let name = format!("branch.{branch}.{key}");
+
+    let mut cmd = Command::new("git");
+    match value {
+        Some(value) => cmd.args(["config", &name, value]),
+        None => cmd.args(["config", "--unset", &name]),
+    };
+
+    app.run_cmd(term, &[], cmd)?;
+    app.inhibit_close_menu();
+    Ok(())
+}
+
+pub(crate) struct BranchConfigDescription;
+impl OpTrait for BranchConfigDescription {
+    fn get_action(&self, _target: &ItemData) -> Option<Action> {
+        Some(Rc::new(|app: &mut App, term: &mut Term| {
+            get_current_branch_name(&app.state.repo)?;
+
+            let mut cmd = Command::new("git");
+            cmd.args(["branch", "--edit-description"]);
+            app.run_cmd_interactive(term, cmd)?;
+            app.inhibit_close_menu();
+            Ok(())
+        }))
+    }
+
+    fn display(&self, state: &State) -> String {
+        describe(state, "description")
+    }
+}
+
+pub(crate) struct BranchConfigUpstream;
+impl OpTrait for BranchConfigUpstream {
+    fn get_action(&self, _target: &ItemData) -> Option<Action> {
+        Some(Rc::new(|app: &mut App, term: &mut Term| {
+            get_current_branch_name(&app.state.repo)?;
+
+            let result = app.pick(
+                term,
+                PickerState::with_refs(PickerParams {
+                    prompt: "Set upstream".into(),
+                    refs: &git::branches(&app.state.repo, None)?,
+                    exclude_ref: git::head_ref(&app.state.repo)?,
+                    default: None,
+                    allow_custom_input: true,
+                }),
+            )?;
+
+            if let Some(data) = result {
+                let mut cmd = Command::new("git");
+                cmd.args(["branch", "--set-upstream-to", data.display()]);
+                app.run_cmd(term, &[], cmd)?;
+                app.inhibit_close_menu();
+            }
+
+            Ok(())
+        }))
+    }
+
+    fn display(&self, state: &State) -> String {
+        match get_branch_config(&state.repo, "remote") {
+            Ok(Some(remote)) => format!("{} (remote {remote})", describe(state, "merge")),
+            _ => describe(state, "merge"),
+        }
+    }
+}
+
+pub(crate) struct BranchConfigUnsetUpstream;
+impl OpTrait for BranchConfigUnsetUpstream {
+    fn get_action(&self, _target: &ItemData) -> Option<Action> {
+        Some(Rc::new(|app: &mut App, term: &mut Term| {
+            get_current_branch_name(&app.state.repo)?;
+
+            let mut cmd = Command::new("git");
+            cmd.args(["branch", "--unset-upstream"]);
+            app.run_cmd(term, &[], cmd)?;
+            app.inhibit_close_menu();
+            Ok(())
+        }))
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "Unset upstream".into()
+    }
+}
+
+/// Cycles through true, false and unset.
+pub(crate) struct BranchConfigRebase;
+impl OpTrait for BranchConfigRebase {
+    fn get_action(&self, _target: &ItemData) -> Option<Action> {
+        Some(Rc::new(|app: &mut App, term: &mut Term| {
+            get_current_branch_name(&app.state.repo)?;
+
+            let next = match get_branch_config(&app.state.repo, "rebase")?.as_deref() {
+                None => Some("true"),
+                Some("true") => Some("false"),
+                Some(_) => None,
+            };
+
+            set_branch_config(app, term, "rebase", next)
+        }))
+    }
+
+    fn display(&self, state: &State) -> String {
+        describe(state, "rebase")
+    }
+}
+
+/// Cycles through the remotes, then unset.
+pub(crate) struct BranchConfigPushRemote;
+impl OpTrait for BranchConfigPushRemote {
+    fn get_action(&self, _target: &ItemData) -> Option<Action> {
+        Some(Rc::new(|app: &mut App, term: &mut Term| {
+            get_current_branch_name(&app.state.repo)?;
+
+            let current = get_branch_config(&app.state.repo, "pushRemote")?;
+            let remotes = remote_names(&app.state.repo)?;
+
+            let next = match current
+                .as_ref()
+                .and_then(|current| remotes.iter().position(|remote| remote == current))
+            {
+                Some(i) => remotes.get(i + 1),
+                None => remotes.first(),
+            };
+
+            if next.is_none() && current.is_none() {
+                return Ok(());
+            }
+
+            set_branch_config(app, term, "pushRemote", next.map(String::as_str))
+        }))
+    }
+
+    fn display(&self, state: &State) -> String {
+        describe(state, "pushRemote")
+    }
+}
@@ -69,21 +69,36 @@ impl OpTrait for CherryPick {
             )?;
 
             if let Some(data) = result {
-                cherry_pick(app, term, data.display())?;
+                cherry_pick(app, term, &[data.display()])?;
             }
             Ok(())
         }))
     }
 
+    fn get_marked_action(&self, targets: &[&ItemData]) -> Option<Action> {
+        // Marked in log order, newest first, but they're picked oldest first
+        let mut oids = super::commit_oids(targets)?;
+        oids.reverse();
+
+        Some(Rc::new(move |app: &mut App, term: &mut Term| {
+            let oids = oids.iter().map(String::as_str).collect::<Vec<_>>();
+            cherry_pick(app, term, &oids)
+        }))
+    }
+
+    fn acts_on_marks(&self) -> bool {
+        true
+    }
+
     fn display(&self, _state: &State) -> String {
         "Cherry-pick commit(s)".into()
     }
 }
 
-fn cherry_pick(app: &mut App, term: &mut Term, input: &str) -> Res<()> {
+fn cherry_pick(app: &mut App, term: &mut Term, inputs: &[&str]) -> Res<()> {
     let mut cmd = Command::new("git");
     cmd.arg("cherry-pick");
     cmd.args(app.state.pending_menu.as_ref().unwrap().args());
-    cmd.arg(input);
+    cmd.args(inputs);
     app.run_cmd_interactive(term, cmd)
 }
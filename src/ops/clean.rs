@@ -0,0 +1,77 @@
+use super::{Action, OpTrait, confirm_with_preview};
+use crate::{
+    Res,
+    app::{App, State},
+    git,
+    item_data::ItemData,
+    menu::arg::{Arg, any_string, split_words},
+    screen,
+    term::Term,
+};
+use std::{path::PathBuf, process::Command, rc::Rc, sync::Arc};
+
+pub(crate) fn init_args() -> Vec<Arg> {
+    vec![
+        Arg::new_flag("-d", "Also clean untracked directories", false),
+        Arg::new_flag("-x", "Also clean ignored files", false),
+        Arg::new_flag("-X", "Only clean ignored files", false),
+        Arg::new_arg("--", "Limit to files", None, any_string),
+    ]
+}
+
+pub(crate) struct Clean;
+impl OpTrait for Clean {
+    fn get_action(&self, target: &ItemData) -> Option<Action> {
+        // Without a path limit, cleaning from an untracked file only cleans that file
+        let selected_path = match target {
+            ItemData::Untracked(path) => Some(path.clone()),
+            _ => None,
+        };
+
+        Some(Rc::new(move |app: &mut App, term: &mut Term| {
+            clean(app, term, selected_path.clone())
+        }))
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "Clean".into()
+    }
+}
+
+fn clean(app: &mut App, term: &mut Term, selected_path: Option<PathBuf>) -> Res<()> {
+    let args = app.state.pending_menu.as_ref().map(|m| &m.args);
+    let arg = |name: &str| {
+        args.and_then(|args| args.get(name))
+            .filter(|arg| arg.is_active())
+    };
+
+    let flags = ["-d", "-x", "-X"]
+        .into_iter()
+        .filter(|flag| arg(flag).is_some())
+        .map(String::from)
+        .collect::<Vec<_>>();
+
+    let paths = match arg("--").and_then(|arg| arg.value_as::<String>()) {
+        Some(paths) => split_words(paths),
+        None => selected_path
+            .into_iter()
+            .map(|path| path.to_string_lossy().into_owned())
+            .collect::<Vec<_>>(),
+    };
+
+    let preview = git::clean_preview(&app.state.repo, &flags, &paths)?;
+    if preview.is_empty() {
+        app.display_info("Nothing to clean");
+        return Ok(());
+    }
+
+    let preview = screen::clean::create(Arc::clone(&app.state.config), app.screen().size, preview)?;
+    confirm_with_preview(app, term, preview, "Really remove these files? (y or n)")?;
+
+    let mut cmd = Command::new("git");
+    cmd.args(["clean", "--force"]);
+    cmd.args(&flags);
+    cmd.arg("--");
+    cmd.args(&paths);
+    app.run_cmd(term, &[], cmd)
+}
@@ -36,7 +36,7 @@ impl OpTrait for Commit {
             cmd.args(["commit"]);
             cmd.args(app.state.pending_menu.as_ref().unwrap().args());
             app.run_cmd_interactive(term, cmd)?;
-            Ok(())
+            super::rebase::continue_split(app, term)
         }))
     }
 
@@ -53,7 +53,7 @@ impl OpTrait for CommitAmend {
             cmd.args(["commit", "--amend"]);
             cmd.args(app.state.pending_menu.as_ref().unwrap().args());
             app.run_cmd_interactive(term, cmd)?;
-            Ok(())
+            super::rebase::continue_split(app, term)
         }))
     }
 
@@ -70,7 +70,7 @@ impl OpTrait for CommitExtend {
             cmd.args(["commit", "--amend", "--no-edit"]);
             cmd.args(app.state.pending_menu.as_ref().unwrap().args());
             app.run_cmd_interactive(term, cmd)?;
-            Ok(())
+            super::rebase::continue_split(app, term)
         }))
     }
 
@@ -88,7 +88,8 @@ impl OpTrait for CommitFixup {
 
                 Some(Rc::new(move |app: &mut App, term: &mut Term| {
                     let args = app.state.pending_menu.as_ref().unwrap().args();
-                    app.run_cmd_interactive(term, commit_fixup_cmd(&args, &rev))
+                    app.run_cmd_interactive(term, commit_fixup_cmd(&args, &rev))?;
+                    super::rebase::continue_split(app, term)
                 }))
             }
             _ => None,
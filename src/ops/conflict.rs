@@ -0,0 +1,135 @@
+use super::{Action, OpTrait};
+use crate::{
+    app::{App, State},
+    error::Error,
+    git::conflict::{ConflictFile, Resolution, read_conflicts},
+    item_data::ItemData,
+    term::Term,
+};
+use std::{fs, path::PathBuf, process::Command, rc::Rc};
+
+pub(crate) struct TakeOurs;
+impl OpTrait for TakeOurs {
+    fn get_action(&self, target: &ItemData) -> Option<Action> {
+        match target {
+            ItemData::Unmerged { path, conflict } if conflict.has_ours() => {
+                Some(checkout_side(path.clone(), "--ours"))
+            }
+            ItemData::ConflictRegion { file, region_i } => {
+                Some(resolve_region(Rc::clone(file), *region_i, Resolution::Ours))
+            }
+            _ => None,
+        }
+    }
+
+    fn is_target_op(&self) -> bool {
+        true
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "Take ours".into()
+    }
+}
+
+pub(crate) struct TakeTheirs;
+impl OpTrait for TakeTheirs {
+    fn get_action(&self, target: &ItemData) -> Option<Action> {
+        match target {
+            ItemData::Unmerged { path, conflict } if conflict.has_theirs() => {
+                Some(checkout_side(path.clone(), "--theirs"))
+            }
+            ItemData::ConflictRegion { file, region_i } => Some(resolve_region(
+                Rc::clone(file),
+                *region_i,
+                Resolution::Theirs,
+            )),
+            _ => None,
+        }
+    }
+
+    fn is_target_op(&self) -> bool {
+        true
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "Take theirs".into()
+    }
+}
+
+fn checkout_side(path: PathBuf, side: &'static str) -> Action {
+    Rc::new(move |app: &mut App, term: &mut Term| {
+        let mut cmd = Command::new("git");
+        cmd.args(["checkout", side, "--"]);
+        cmd.arg(&path);
+
+        app.run_cmd(term, &[], cmd)
+    })
+}
+
+pub(crate) struct TakeBoth;
+impl OpTrait for TakeBoth {
+    fn get_action(&self, target: &ItemData) -> Option<Action> {
+        match target {
+            ItemData::ConflictRegion { file, region_i } => {
+                Some(resolve_region(Rc::clone(file), *region_i, Resolution::Both))
+            }
+            _ => None,
+        }
+    }
+
+    fn is_target_op(&self) -> bool {
+        true
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "Take both".into()
+    }
+}
+
+/// Rewrites the file with a single conflict region replaced, leaving the others be.
+/// The file is read again first, so edits made since it was shown aren't lost.
+fn resolve_region(file: Rc<ConflictFile>, region_i: usize, resolution: Resolution) -> Action {
+    Rc::new(move |app: &mut App, _term: &mut Term| {
+        let workdir = app.state.repo.workdir().ok_or(Error::NoRepoWorkdir)?;
+        let current = read_conflicts(workdir, &file.path)?;
+
+        if current.region_text(region_i) != file.region_text(region_i) {
+            app.update_screens()?;
+            return Err(Error::ConflictRegionChanged);
+        }
+
+        fs::write(
+            workdir.join(&current.path),
+            current.resolve(region_i, resolution),
+        )
+        .map_err(Error::WriteConflictFile)?;
+
+        app.update_screens()
+    })
+}
+
+pub(crate) struct Mergetool;
+impl OpTrait for Mergetool {
+    fn get_action(&self, target: &ItemData) -> Option<Action> {
+        let ItemData::Unmerged { path, .. } = target else {
+            return None;
+        };
+
+        let path = path.clone();
+        Some(Rc::new(move |app: &mut App, term: &mut Term| {
+            let mut cmd = Command::new("git");
+            cmd.args(["mergetool", "--no-prompt", "--"]);
+            cmd.arg(&path);
+
+            app.run_cmd_interactive(term, cmd)
+        }))
+    }
+
+    fn is_target_op(&self) -> bool {
+        true
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "Mergetool".into()
+    }
+}
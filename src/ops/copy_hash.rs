@@ -10,6 +10,14 @@ impl OpTrait for CopyHash {
             _ => None,
         }
     }
+    fn get_marked_action(&self, targets: &[&ItemData]) -> Option<Action> {
+        super::commit_oids(targets).map(|oids| copy_hash(oids.join(" ")))?
+    }
+
+    fn acts_on_marks(&self) -> bool {
+        true
+    }
+
     fn is_target_op(&self) -> bool {
         true
     }
@@ -24,7 +32,11 @@ fn copy_hash(r: String) -> Option<Action> {
         match &mut app.state.clipboard {
             Some(cb) => {
                 cb.set_text(r.clone()).map_err(Error::Clipboard)?;
-                app.display_info("Commit hash copied to clipboard");
+                app.display_info(if r.contains(' ') {
+                    "Commit hashes copied to clipboard"
+                } else {
+                    "Commit hash copied to clipboard"
+                });
             }
             None => app.display_error("Clipboard not available"),
         }
@@ -0,0 +1,178 @@
+use super::{Action, OpTrait, pick_rev};
+use crate::{
+    Res,
+    app::{App, PromptParams, State},
+    error::Error,
+    git::{self, diff::DiffType},
+    gitu_diff::Status,
+    item_data::{ItemData, Rev},
+    menu::arg::Arg,
+    screen,
+    term::Term,
+};
+use std::{rc::Rc, sync::Arc};
+
+pub(crate) fn init_args() -> Vec<Arg> {
+    vec![]
+}
+
+pub(crate) struct DiffRange;
+impl OpTrait for DiffRange {
+    fn get_action(&self, target: &ItemData) -> Option<Action> {
+        let selected = target.rev();
+
+        Some(Rc::new(move |app: &mut App, term: &mut Term| {
+            let Some((from, to)) = pick_range(app, term, selected.clone())? else {
+                return Ok(());
+            };
+
+            goto_diff_screen(app, term, DiffType::Range { from, to })
+        }))
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "Range (A..B)".into()
+    }
+}
+
+pub(crate) struct DiffMergeBase;
+impl OpTrait for DiffMergeBase {
+    fn get_action(&self, target: &ItemData) -> Option<Action> {
+        let selected = target.rev();
+
+        Some(Rc::new(move |app: &mut App, term: &mut Term| {
+            let Some((from, to)) = pick_range(app, term, selected.clone())? else {
+                return Ok(());
+            };
+
+            goto_diff_screen(app, term, DiffType::MergeBase { from, to })
+        }))
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "Since merge-base (A...B)".into()
+    }
+}
+
+pub(crate) struct DiffWorktree;
+impl OpTrait for DiffWorktree {
+    fn get_action(&self, target: &ItemData) -> Option<Action> {
+        let selected = target.rev();
+
+        Some(Rc::new(move |app: &mut App, term: &mut Term| {
+            let default = default_rev(app, selected.clone())?;
+            let Some(rev) = pick_rev(app, term, "Diff worktree against", default)? else {
+                return Ok(());
+            };
+
+            goto_diff_screen(app, term, DiffType::WorkdirToCommit(rev))
+        }))
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "Worktree against commit".into()
+    }
+}
+
+pub(crate) struct DiffIndex;
+impl OpTrait for DiffIndex {
+    fn get_action(&self, target: &ItemData) -> Option<Action> {
+        let selected = target.rev();
+
+        Some(Rc::new(move |app: &mut App, term: &mut Term| {
+            let default = default_rev(app, selected.clone())?;
+            let Some(rev) = pick_rev(app, term, "Diff index against", default)? else {
+                return Ok(());
+            };
+
+            goto_diff_screen(app, term, DiffType::IndexToCommit(rev))
+        }))
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "Index against commit".into()
+    }
+}
+
+pub(crate) struct DiffPath;
+impl OpTrait for DiffPath {
+    fn get_action(&self, target: &ItemData) -> Option<Action> {
+        let selected = target.rev();
+        let selected_path = match target {
+            ItemData::Untracked(path) => Some(path.to_string_lossy().into_owned()),
+            ItemData::Delta { diff, file_i }
+            | ItemData::Hunk { diff, file_i, .. }
+            | ItemData::HunkLine { diff, file_i, .. } => {
+                let header = &diff.file_diffs[*file_i].header;
+                let path = match header.status {
+                    Status::Deleted => &header.old_file,
+                    _ => &header.new_file,
+                };
+                Some(path.fmt(&diff.text).into_owned())
+            }
+            _ => None,
+        };
+
+        Some(Rc::new(move |app: &mut App, term: &mut Term| {
+            let default_path = selected_path.clone();
+            let path = app.prompt(
+                term,
+                &PromptParams {
+                    prompt: "Diff path",
+                    create_default_value: Box::new(move |_| default_path.clone()),
+                    ..Default::default()
+                },
+            )?;
+
+            if path.is_empty() {
+                return Err(Error::DiffPathRequired);
+            }
+
+            let Some((from, to)) = pick_range(app, term, selected.clone())? else {
+                return Ok(());
+            };
+
+            goto_diff_screen(app, term, DiffType::Path { from, to, path })
+        }))
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "Path between revisions".into()
+    }
+}
+
+/// Picks the two ends of a range, from the selected revision to `HEAD` by default.
+fn pick_range(
+    app: &mut App,
+    term: &mut Term,
+    selected: Option<Rev>,
+) -> Res<Option<(String, String)>> {
+    let from_default = default_rev(app, selected)?;
+    let Some(from) = pick_rev(app, term, "Diff from", from_default)? else {
+        return Ok(None);
+    };
+
+    let to_default = git::head(&app.state.repo)?;
+    let Some(to) = pick_rev(app, term, "Diff to", to_default)? else {
+        return Ok(None);
+    };
+
+    Ok(Some((from, to)))
+}
+
+fn default_rev(app: &App, selected: Option<Rev>) -> Res<Rev> {
+    match selected {
+        Some(rev) => Ok(rev),
+        None => git::head(&app.state.repo),
+    }
+}
+
+fn goto_diff_screen(app: &mut App, term: &mut Term, diff_type: DiffType) -> Res<()> {
+    app.state.screens.push(screen::diff::create(
+        Arc::clone(&app.state.config),
+        Rc::clone(&app.state.repo),
+        term.size().map_err(Error::Term)?,
+        diff_type,
+    )?);
+    Ok(())
+}
@@ -1,9 +1,9 @@
-use super::{Action, OpTrait, confirm};
+use super::{Action, MarkedTarget, OpTrait, confirm};
 use crate::{
     Res,
     app::{App, State},
     config::ConfirmDiscardOption,
-    git::diff::{DiffType, PatchMode},
+    git::diff::{Diff, DiffType, PatchMode},
     item_data::{ItemData, Ref},
     term::Term,
 };
@@ -12,56 +12,38 @@ use std::{path::PathBuf, process::Command, rc::Rc};
 pub(crate) struct Discard;
 impl OpTrait for Discard {
     fn get_action(&self, target: &ItemData) -> Option<Action> {
-        let action = match target {
+        match target {
             ItemData::Reference {
                 kind: Ref::Head(branch),
                 ..
-            } => discard_branch(branch.clone()),
-            ItemData::Untracked(file) => clean_file(file.clone()),
-            ItemData::Delta { diff, file_i } => {
-                let patch = diff.format_file_patch(*file_i);
-                match diff.diff_type {
-                    DiffType::WorkdirToIndex => reverse_worktree(patch),
-                    DiffType::IndexToTree => reverse_index_and_worktree(patch),
-                    DiffType::TreeToTree => reverse_index_and_worktree(patch),
-                }
-            }
-            ItemData::Hunk {
-                diff,
-                file_i,
-                hunk_i,
-            } => {
-                let patch = diff.format_hunk_patch(*file_i, *hunk_i);
-                match diff.diff_type {
-                    DiffType::WorkdirToIndex => reverse_worktree(patch),
-                    DiffType::IndexToTree => reverse_index_and_worktree(patch),
-                    DiffType::TreeToTree => reverse_index_and_worktree(patch),
-                }
-            }
-            ItemData::HunkLine {
-                diff,
-                file_i,
-                hunk_i,
-                line_i,
-                ..
-            } => {
-                let patch = diff.format_line_patch(
-                    *file_i,
-                    *hunk_i,
-                    *line_i..(line_i + 1),
-                    PatchMode::Reverse,
-                );
-
-                match diff.diff_type {
-                    DiffType::WorkdirToIndex => reverse_worktree(patch),
-                    DiffType::IndexToTree => reverse_index_and_worktree(patch),
-                    DiffType::TreeToTree => reverse_index_and_worktree(patch),
-                }
-            }
-            _ => return None,
-        };
-
-        Some(action)
+            } => Some(discard_branch(branch.clone())),
+            _ => discard(target).map(confirmed),
+        }
+    }
+
+    fn get_marked_action(&self, targets: &[&ItemData]) -> Option<Action> {
+        let actions = super::marked_targets(targets)?
+            .into_iter()
+            .map(|target| match target {
+                MarkedTarget::Item(target) => discard(target),
+                MarkedTarget::Lines {
+                    diff,
+                    file_i,
+                    hunk_i,
+                    line_is,
+                } => discard_patch(
+                    diff,
+                    diff.format_line_patch(file_i, hunk_i, &line_is, PatchMode::Reverse),
+                ),
+            })
+            .collect::<Option<_>>()?;
+
+        // One confirmation for all of them
+        Some(confirmed(super::sequence(actions)))
+    }
+
+    fn acts_on_marks(&self) -> bool {
+        true
     }
 
     fn is_target_op(&self) -> bool {
@@ -73,6 +55,49 @@ impl OpTrait for Discard {
     }
 }
 
+/// Discards changes, without asking for confirmation
+fn discard(target: &ItemData) -> Option<Action> {
+    match target {
+        ItemData::Untracked(file) => Some(clean_file(file.clone())),
+        ItemData::Delta { diff, file_i } => discard_patch(diff, diff.format_file_patch(*file_i)),
+        ItemData::Hunk {
+            diff,
+            file_i,
+            hunk_i,
+        } => discard_patch(diff, diff.format_hunk_patch(*file_i, *hunk_i)),
+        ItemData::HunkLine {
+            diff,
+            file_i,
+            hunk_i,
+            line_i,
+            ..
+        } => discard_patch(
+            diff,
+            diff.format_line_patch(*file_i, *hunk_i, &[*line_i], PatchMode::Reverse),
+        ),
+        _ => None,
+    }
+}
+
+/// Reverses a patch in the worktree or the index. Ranges, merge bases and paths can't be discarded.
+fn discard_patch(diff: &Diff, patch: String) -> Option<Action> {
+    match diff.diff_type {
+        DiffType::WorkdirToIndex | DiffType::WorkdirToCommit(_) => Some(reverse_worktree(patch)),
+        DiffType::IndexToTree | DiffType::TreeToTree => Some(reverse_index_and_worktree(patch)),
+        DiffType::Range { .. }
+        | DiffType::MergeBase { .. }
+        | DiffType::IndexToCommit(_)
+        | DiffType::Path { .. } => None,
+    }
+}
+
+fn confirmed(mut action: Action) -> Action {
+    Rc::new(move |app, term| {
+        confirm_discard(app, term)?;
+        Rc::get_mut(&mut action).unwrap()(app, term)
+    })
+}
+
 fn discard_branch(branch: String) -> Action {
     Rc::new(move |app, term| {
         confirm(app, term, "Really discard? (y or n)")?;
@@ -82,8 +107,6 @@ fn discard_branch(branch: String) -> Action {
 
 fn clean_file(file: PathBuf) -> Action {
     Rc::new(move |app, term| {
-        confirm_discard(app, term)?;
-
         let mut cmd = Command::new("git");
         cmd.args(["clean", "--force"]);
         cmd.arg(&file);
@@ -96,8 +119,6 @@ fn reverse_worktree(patch: String) -> Action {
     let patch_bytes = patch.into_bytes();
 
     Rc::new(move |app, term| {
-        confirm_discard(app, term)?;
-
         let mut cmd = Command::new("git");
         cmd.args(["apply", "--reverse", "--recount"]);
         app.run_cmd(term, &patch_bytes, cmd)
@@ -108,8 +129,6 @@ fn reverse_index_and_worktree(patch: String) -> Action {
     let patch_bytes = patch.into_bytes();
 
     Rc::new(move |app, term| {
-        confirm_discard(app, term)?;
-
         let mut cmd = Command::new("git");
         cmd.args(["apply", "--reverse", "--index", "--recount"]);
         app.run_cmd(term, &patch_bytes, cmd)
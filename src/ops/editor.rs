@@ -58,7 +58,10 @@ impl OpTrait for OpenMenu {
 pub(crate) struct Refresh;
 impl OpTrait for Refresh {
     fn get_action(&self, _target: &ItemData) -> Option<Action> {
-        Some(Rc::new(|app, _term| app.update_screens()))
+        Some(Rc::new(|app, term| {
+            app.update_screens()?;
+            super::rebase::continue_split(app, term)
+        }))
     }
 
     fn display(&self, _state: &State) -> String {
@@ -155,6 +158,39 @@ impl OpTrait for ToggleSection {
     }
 }
 
+pub(crate) struct ToggleMark;
+impl OpTrait for ToggleMark {
+    fn get_action(&self, _target: &ItemData) -> Option<Action> {
+        Some(Rc::new(|app, _term| {
+            app.screen_mut().toggle_mark();
+            Ok(())
+        }))
+    }
+
+    fn display(&self, state: &State) -> String {
+        let screen = state.screens.last().unwrap();
+        if screen.is_marked(screen.get_selected_item()) {
+            "Unmark".into()
+        } else {
+            "Mark".into()
+        }
+    }
+}
+
+pub(crate) struct ClearMarks;
+impl OpTrait for ClearMarks {
+    fn get_action(&self, _target: &ItemData) -> Option<Action> {
+        Some(Rc::new(|app, _term| {
+            app.screen_mut().clear_marks();
+            Ok(())
+        }))
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "Clear marks".into()
+    }
+}
+
 pub(crate) struct MoveUp;
 impl OpTrait for MoveUp {
     fn get_action(&self, _target: &ItemData) -> Option<Action> {
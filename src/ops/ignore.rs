@@ -0,0 +1,157 @@
+use super::{Action, OpTrait, confirm_with_preview};
+use crate::{
+    Res,
+    app::{App, State},
+    error::Error,
+    git,
+    item_data::ItemData,
+    picker::{PickerData, PickerItem, PickerState},
+    screen,
+    term::Term,
+};
+use std::{
+    path::{Path, PathBuf},
+    rc::Rc,
+    sync::Arc,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum IgnoreFile {
+    /// `.gitignore` at the top of the worktree
+    Toplevel,
+    /// `.gitignore` in the directory of the selected file
+    Subdirectory,
+    /// `.git/info/exclude`
+    Private,
+    /// `core.excludesFile`
+    Global,
+}
+
+pub(crate) struct Ignore(pub IgnoreFile);
+impl OpTrait for Ignore {
+    fn get_action(&self, target: &ItemData) -> Option<Action> {
+        let file = self.0;
+        let paths = match target {
+            ItemData::Untracked(path) => vec![path.clone()],
+            ItemData::AllUntracked(paths) if file != IgnoreFile::Subdirectory => paths.clone(),
+            _ => return None,
+        };
+
+        Some(Rc::new(move |app: &mut App, term: &mut Term| {
+            ignore(app, term, file, &paths)
+        }))
+    }
+
+    fn is_target_op(&self) -> bool {
+        true
+    }
+
+    fn display(&self, _state: &State) -> String {
+        match self.0 {
+            IgnoreFile::Toplevel => "Ignore in top-level .gitignore",
+            IgnoreFile::Subdirectory => "Ignore in subdirectory .gitignore",
+            IgnoreFile::Private => "Ignore privately (.git/info/exclude)",
+            IgnoreFile::Global => "Ignore globally (core.excludesFile)",
+        }
+        .into()
+    }
+}
+
+fn ignore(app: &mut App, term: &mut Term, file: IgnoreFile, paths: &[PathBuf]) -> Res<()> {
+    let paths = paths
+        .iter()
+        .map(|path| path.to_string_lossy().into_owned())
+        .collect::<Vec<_>>();
+
+    let repo = &app.state.repo;
+    let workdir = repo.workdir().ok_or(Error::NoRepoWorkdir)?;
+
+    // Patterns in a subdirectory's .gitignore are relative to that directory
+    let base = match file {
+        IgnoreFile::Subdirectory => parent_dir(&paths[0]),
+        _ => "",
+    };
+
+    let ignore_file = match file {
+        IgnoreFile::Toplevel | IgnoreFile::Subdirectory => workdir.join(base).join(".gitignore"),
+        IgnoreFile::Private => repo.commondir().join("info").join("exclude"),
+        IgnoreFile::Global => git::ignore::excludes_file(repo)?,
+    };
+
+    let file_display = ignore_file
+        .strip_prefix(workdir)
+        .unwrap_or(&ignore_file)
+        .to_string_lossy()
+        .into_owned();
+
+    let choices = choices(&paths, base);
+    let items = choices
+        .iter()
+        .map(|(label, _)| PickerItem::new(label.clone(), PickerData::Item(label.clone())))
+        .collect();
+
+    let patterns = match app.pick(term, PickerState::new("Ignore", items, true))? {
+        Some(PickerData::Item(label)) => choices
+            .into_iter()
+            .find(|(choice, _)| *choice == label)
+            .map(|(_, patterns)| patterns)
+            .unwrap_or_default(),
+        Some(PickerData::CustomInput(pattern)) if !pattern.is_empty() => vec![pattern],
+        _ => return Ok(()),
+    };
+
+    let preview = screen::ignore::create(
+        Arc::clone(&app.state.config),
+        app.screen().size,
+        file_display,
+        patterns.clone(),
+    )?;
+    confirm_with_preview(app, term, preview, "Add these entries? (y or n)")?;
+
+    git::ignore::append(&ignore_file, &patterns)?;
+    app.update_screens()
+}
+
+/// What can be ignored: each choice is shown by its label and adds its patterns.
+/// Paths get anchored to `base`, the directory the ignore file applies to.
+fn choices(paths: &[String], base: &str) -> Vec<(String, Vec<String>)> {
+    let relative = |path: &str| path.strip_prefix(base).unwrap_or(path).to_string();
+    let mut choices = vec![];
+
+    if let [path] = paths {
+        let path = relative(path);
+        choices.push((format!("/{path}"), vec![format!("/{path}")]));
+
+        let dir = parent_dir(&path);
+        if !dir.is_empty() {
+            choices.push((format!("/{dir}"), vec![format!("/{dir}")]));
+        }
+    } else {
+        choices.push((
+            format!("All {} untracked paths", paths.len()),
+            paths
+                .iter()
+                .map(|path| format!("/{}", relative(path)))
+                .collect(),
+        ));
+    }
+
+    for path in paths.iter().filter(|path| !path.ends_with('/')) {
+        if let Some(ext) = Path::new(path).extension() {
+            let glob = format!("*.{}", ext.to_string_lossy());
+            if !choices.iter().any(|(label, _)| *label == glob) {
+                choices.push((glob.clone(), vec![glob]));
+            }
+        }
+    }
+
+    choices
+}
+
+/// The directory part of a path, with its trailing slash. Empty at the top level.
+fn parent_dir(path: &str) -> &str {
+    match path.trim_end_matches('/').rfind('/') {
+        Some(i) => &path[..=i],
+        None => "",
+    }
+}
@@ -3,8 +3,10 @@ use crate::{
     Res,
     app::{App, PromptParams, State},
     error::Error,
-    item_data::{ItemData, Rev},
-    menu::arg::{Arg, any_regex, positive_number},
+    gitu_diff::Status,
+    item_data::{ItemData, Ref, Rev},
+    items::LogFilter,
+    menu::arg::{Arg, any_regex, any_string, positive_number, split_words},
     screen,
     term::Term,
 };
@@ -21,7 +23,14 @@ pub(crate) fn init_args() -> Vec<Arg> {
             positive_number,
         ),
         Arg::new_arg("--grep", "Search messages", None, any_regex),
-        // Arg::new_str("-S", "Search occurrences"), // TODO: Implement search
+        Arg::new_arg("--author", "Limit to author", None, any_string),
+        Arg::new_arg("--since", "Limit to commits since", None, any_string),
+        Arg::new_arg("--until", "Limit to commits until", None, any_string),
+        Arg::new_arg("-S", "Search occurrences", None, any_string),
+        Arg::new_arg("-G", "Search changes", None, any_string),
+        Arg::new_flag("--first-parent", "Only follow first parent", false),
+        Arg::new_flag("--no-merges", "Omit merges", false),
+        Arg::new_arg("--", "Limit to files", None, any_string),
     ]
 }
 
@@ -29,8 +38,7 @@ pub(crate) struct LogCurrent;
 impl OpTrait for LogCurrent {
     fn get_action(&self, _target: &ItemData) -> Option<Action> {
         Some(Rc::new(|app: &mut App, _term: &mut Term| {
-            goto_log_screen(app, None);
-            Ok(())
+            goto_log_screen(app, None)
         }))
     }
 
@@ -67,6 +75,75 @@ impl OpTrait for LogOther {
     }
 }
 
+pub(crate) struct Reflog;
+impl OpTrait for Reflog {
+    fn get_action(&self, target: &ItemData) -> Option<Action> {
+        // Selecting a branch, like in the refs screen, picks its reflog over HEAD's
+        let refname = match target {
+            ItemData::Reference {
+                kind: Ref::Head(branch),
+                ..
+            } => format!("refs/heads/{branch}"),
+            _ => "HEAD".to_string(),
+        };
+
+        Some(Rc::new(move |app: &mut App, _term: &mut Term| {
+            let size = app.state.screens.last().unwrap().size;
+            let screen = screen::log::create_reflog(
+                Arc::clone(&app.state.config),
+                Rc::clone(&app.state.repo),
+                size,
+                refname.clone(),
+            )?;
+
+            app.state.screens.push(screen);
+            Ok(())
+        }))
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "reflog".into()
+    }
+}
+
+pub(crate) struct LogFile;
+impl OpTrait for LogFile {
+    fn get_action(&self, target: &ItemData) -> Option<Action> {
+        let path = match target {
+            ItemData::Delta { diff, file_i } | ItemData::Hunk { diff, file_i, .. } => {
+                let header = &diff.file_diffs[*file_i].header;
+                match header.status {
+                    Status::Deleted => header.old_file.fmt(&diff.text).to_string(),
+                    _ => header.new_file.fmt(&diff.text).to_string(),
+                }
+            }
+            ItemData::Untracked(path) => path.to_string_lossy().to_string(),
+            _ => return None,
+        };
+
+        Some(Rc::new(move |app: &mut App, _term: &mut Term| {
+            let size = app.state.screens.last().unwrap().size;
+            let screen = screen::log::create_file_log(
+                Arc::clone(&app.state.config),
+                Rc::clone(&app.state.repo),
+                size,
+                path.clone(),
+            )?;
+
+            app.state.screens.push(screen);
+            Ok(())
+        }))
+    }
+
+    fn is_target_op(&self) -> bool {
+        true
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "file".into()
+    }
+}
+
 fn log_other(app: &mut App, _term: &mut Term, result: &str) -> Res<()> {
     let oid_result = match app.state.repo.revparse_single(result) {
         Ok(rev) => Ok(rev.id()),
@@ -75,38 +152,53 @@ fn log_other(app: &mut App, _term: &mut Term, result: &str) -> Res<()> {
 
     let oid = oid_result?;
 
-    goto_log_screen(app, Some(oid));
-    Ok(())
+    goto_log_screen(app, Some(oid))
 }
 
-fn goto_log_screen(app: &mut App, rev: Option<Oid>) {
-    app.state.screens.drain(1..);
+fn goto_log_screen(app: &mut App, rev: Option<Oid>) -> Res<()> {
     let size = app.state.screens.last().unwrap().size;
-    let limit = *app
-        .state
-        .pending_menu
-        .as_ref()
-        .and_then(|m| m.args.get("-n"))
+    let args = app.state.pending_menu.as_ref().map(|m| &m.args);
+    let arg = |name: &str| {
+        args.and_then(|args| args.get(name))
+            .filter(|arg| arg.is_active())
+    };
+    let string_arg = |name: &str| arg(name).and_then(|arg| arg.value_as::<String>().cloned());
+
+    let limit = *arg("-n")
         .and_then(|arg| arg.value_as::<u32>())
         .unwrap_or(&u32::MAX);
 
-    let msg_regex_menu = app
-        .state
-        .pending_menu
-        .as_ref()
-        .and_then(|m| m.args.get("--grep"));
-
-    let msg_regex = msg_regex_menu.and_then(|arg| arg.value_as::<Regex>().cloned());
-
-    app.state.screens.push(
-        screen::log::create(
-            Arc::clone(&app.state.config),
-            Rc::clone(&app.state.repo),
-            size,
-            limit as usize,
-            rev,
-            msg_regex,
-        )
-        .expect("Couldn't create screen"),
-    );
+    let git_args = [
+        ("--author", "--author="),
+        ("--since", "--since="),
+        ("--until", "--until="),
+        ("-S", "-S"),
+        ("-G", "-G"),
+    ]
+    .into_iter()
+    .filter_map(|(name, flag)| string_arg(name).map(|value| format!("{flag}{value}")))
+    .collect();
+
+    let filter = LogFilter {
+        msg_regex: arg("--grep").and_then(|arg| arg.value_as::<Regex>().cloned()),
+        first_parent: arg("--first-parent").is_some(),
+        no_merges: arg("--no-merges").is_some(),
+        git_args,
+        paths: string_arg("--")
+            .map(|paths| split_words(&paths))
+            .unwrap_or_default(),
+    };
+
+    let screen = screen::log::create(
+        Arc::clone(&app.state.config),
+        Rc::clone(&app.state.repo),
+        size,
+        limit as usize,
+        rev,
+        filter,
+    )?;
+
+    app.state.screens.drain(1..);
+    app.state.screens.push(screen);
+    Ok(())
 }
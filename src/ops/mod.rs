@@ -3,25 +3,38 @@ use serde::{Deserialize, Serialize};
 use crate::{
     Res,
     app::{App, State},
+    error::Error,
+    git::{self, diff::Diff, rebase_todo::TodoAction},
     item_data::{ItemData, Rev},
     menu::Menu,
+    picker::{PickerParams, PickerState},
+    screen::Screen,
     term::Term,
 };
 use std::{fmt::Display, rc::Rc};
 
 pub(crate) mod apply;
+pub(crate) mod bisect;
+pub(crate) mod blame;
 pub(crate) mod branch;
+pub(crate) mod branch_config;
 pub(crate) mod cherry_pick;
+pub(crate) mod clean;
 pub(crate) mod commit;
+pub(crate) mod conflict;
 pub(crate) mod copy_hash;
+pub(crate) mod diff;
 pub(crate) mod discard;
 pub(crate) mod editor;
 pub(crate) mod fetch;
+pub(crate) mod ignore;
 pub(crate) mod log;
 pub(crate) mod merge;
+pub(crate) mod patch;
 pub(crate) mod pull;
 pub(crate) mod push;
 pub(crate) mod rebase;
+pub(crate) mod rebase_todo;
 pub(crate) mod remote;
 pub(crate) mod reset;
 pub(crate) mod reverse;
@@ -30,7 +43,10 @@ pub(crate) mod show;
 pub(crate) mod show_refs;
 pub(crate) mod stage;
 pub(crate) mod stash;
+pub(crate) mod submodule;
+pub(crate) mod tag;
 pub(crate) mod unstage;
+pub(crate) mod worktree;
 
 pub(crate) type Action = Rc<dyn FnMut(&mut App, &mut Term) -> Res<()>>;
 
@@ -39,6 +55,18 @@ pub(crate) trait OpTrait {
     /// This indirection allows Gitu to show a contextual menu of applicable actions.
     fn get_action(&self, target: &ItemData) -> Option<Action>;
 
+    /// Like `get_action`, but acting on all items marked on the screen at once.
+    /// Returns `None` when the op can't act on these items together.
+    fn get_marked_action(&self, _targets: &[&ItemData]) -> Option<Action> {
+        None
+    }
+
+    /// Whether `get_marked_action` is implemented. While items are marked,
+    /// these ops never fall back to acting on the item under the cursor.
+    fn acts_on_marks(&self) -> bool {
+        false
+    }
+
     /// This indicates whether the Op is meant to read and
     /// act on TargetData. Those are listed differently in the help menu.
     fn is_target_op(&self) -> bool {
@@ -48,15 +76,156 @@ pub(crate) trait OpTrait {
     fn display(&self, state: &State) -> String;
 }
 
+/// The action of an op on the marked items of a screen, if any are marked and the op acts on marks.
+/// Errors if it can't act on this particular set of marked items.
+pub(crate) fn marked_action(implementation: &dyn OpTrait, screen: &Screen) -> Res<Option<Action>> {
+    let targets = screen.marked_items();
+    if targets.is_empty() || !implementation.acts_on_marks() {
+        return Ok(None);
+    }
+
+    implementation
+        .get_marked_action(&targets)
+        .map(Some)
+        .ok_or(Error::MarkedItemsUnsupported)
+}
+
+/// The hashes of the targets, as long as all of them are commits.
+pub(crate) fn commit_oids(targets: &[&ItemData]) -> Option<Vec<String>> {
+    targets
+        .iter()
+        .map(|target| match target {
+            ItemData::Commit { oid, .. } => Some(oid.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// A marked item to act on, with the marked lines of a hunk gathered into one.
+pub(crate) enum MarkedTarget<'a> {
+    Item(&'a ItemData),
+    Lines {
+        diff: &'a Rc<Diff>,
+        file_i: usize,
+        hunk_i: usize,
+        line_is: Vec<usize>,
+    },
+}
+
+/// Gathers the marked lines of each hunk, so they can be applied as one patch.
+/// Once part of a hunk is applied, a patch made from the rest of it no longer applies,
+/// so this returns `None` if a marked file or hunk overlaps with other marked items.
+pub(crate) fn marked_targets<'a>(targets: &[&'a ItemData]) -> Option<Vec<MarkedTarget<'a>>> {
+    let patch_part = |target: &ItemData| match target {
+        ItemData::Delta { diff, file_i } => Some((Rc::as_ptr(diff), *file_i, None)),
+        ItemData::Hunk {
+            diff,
+            file_i,
+            hunk_i,
+        } => Some((Rc::as_ptr(diff), *file_i, Some((*hunk_i, false)))),
+        ItemData::HunkLine {
+            diff,
+            file_i,
+            hunk_i,
+            ..
+        } => Some((Rc::as_ptr(diff), *file_i, Some((*hunk_i, true)))),
+        _ => None,
+    };
+
+    let parts = targets
+        .iter()
+        .filter_map(|target| patch_part(target))
+        .collect::<Vec<_>>();
+
+    for (i, (diff, file_i, hunk)) in parts.iter().enumerate() {
+        for (other_diff, other_file_i, other_hunk) in &parts[(i + 1)..] {
+            if diff != other_diff || file_i != other_file_i {
+                continue;
+            }
+
+            let overlaps = match (hunk, other_hunk) {
+                (Some((hunk_i, is_line)), Some((other_hunk_i, other_is_line))) => {
+                    hunk_i == other_hunk_i && !(*is_line && *other_is_line)
+                }
+                _ => true,
+            };
+
+            if overlaps {
+                return None;
+            }
+        }
+    }
+
+    let mut marked = Vec::<MarkedTarget>::new();
+    for target in targets {
+        let ItemData::HunkLine {
+            diff,
+            file_i,
+            hunk_i,
+            line_i,
+            ..
+        } = target
+        else {
+            marked.push(MarkedTarget::Item(target));
+            continue;
+        };
+
+        let same_hunk = marked.iter_mut().find_map(|m| match m {
+            MarkedTarget::Lines {
+                diff: d,
+                file_i: f,
+                hunk_i: h,
+                line_is,
+            } if Rc::ptr_eq(d, diff) && f == file_i && h == hunk_i => Some(line_is),
+            _ => None,
+        });
+
+        match same_hunk {
+            Some(line_is) => line_is.push(*line_i),
+            None => marked.push(MarkedTarget::Lines {
+                diff,
+                file_i: *file_i,
+                hunk_i: *hunk_i,
+                line_is: vec![*line_i],
+            }),
+        }
+    }
+
+    Some(marked)
+}
+
+/// Runs actions one after another, stopping at the first error.
+pub(crate) fn sequence(mut actions: Vec<Action>) -> Action {
+    Rc::new(move |app, term| {
+        for action in actions.iter_mut() {
+            Rc::get_mut(action).unwrap()(app, term)?;
+        }
+        Ok(())
+    })
+}
+
 #[derive(Clone, PartialOrd, Ord, PartialEq, Eq, Debug, Serialize, Deserialize, strum::AsRefStr)]
 #[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
 pub(crate) enum Op {
     AddRemote,
     Checkout,
+    CheckoutLocalBranch,
     CheckoutNewBranch,
     Spinoff,
+    CreateBranch,
+    ResetBranch,
     Delete,
+    BranchConfigDescription,
+    BranchConfigUpstream,
+    BranchConfigUnsetUpstream,
+    BranchConfigRebase,
+    BranchConfigPushRemote,
+    Clean,
+    IgnoreToplevel,
+    IgnoreSubdirectory,
+    IgnorePrivate,
+    IgnoreGlobal,
     Commit,
     CommitAmend,
     CommitExtend,
@@ -88,8 +257,16 @@ pub(crate) enum Op {
     CommitFixup,
     CommitInstantFixup,
     LogOther,
+    Reflog,
+    LogFile,
     RebaseAutosquash,
     RebaseInteractive,
+    RebaseReword,
+    RebaseEdit,
+    RebaseDrop,
+    RebaseMoveUp,
+    RebaseMoveDown,
+    RebaseSplit,
     ResetSoft,
     ResetMixed,
     ResetHard,
@@ -102,16 +279,67 @@ pub(crate) enum Op {
     Merge,
     MergeAbort,
     MergeContinue,
+    TakeOurs,
+    TakeTheirs,
+    TakeBoth,
+    Mergetool,
+    FormatPatch,
+    Am,
+    AmContinue,
+    AmSkip,
+    AmAbort,
+    CreateTag,
+    CreateAnnotatedTag,
+    DeleteTag,
+    DeleteRemoteTag,
+    PushTag,
+    PushAllTags,
+    AddWorktree,
+    ListWorktrees,
+    RemoveWorktree,
+    PruneWorktrees,
+    SwitchWorktree,
+    InitSubmodules,
+    UpdateSubmodules,
+    SyncSubmodules,
+    FetchSubmodules,
+    DiffRange,
+    DiffMergeBase,
+    DiffWorktree,
+    DiffIndex,
+    DiffPath,
+    BisectStart,
+    BisectGood,
+    BisectBad,
+    BisectSkip,
+    BisectReset,
+    BisectRun,
+    RebaseTodoPick,
+    RebaseTodoReword,
+    RebaseTodoEdit,
+    RebaseTodoSquash,
+    RebaseTodoFixup,
+    RebaseTodoDrop,
+    RebaseTodoExec,
+    RebaseTodoMoveUp,
+    RebaseTodoMoveDown,
+    RebaseTodoConfirm,
+    RebaseTodoAbort,
 
     Stage,
+    StageEdit,
     Unstage,
     Show,
     Discard,
     Apply,
     Reverse,
     CopyHash,
+    Blame,
+    BlameParent,
 
     ToggleSection,
+    ToggleMark,
+    ClearMarks,
     MoveUp,
     MoveDown,
     MoveUpLine,
@@ -141,6 +369,8 @@ impl Op {
             Op::Refresh => Box::new(editor::Refresh),
             Op::ToggleArg(name) => Box::new(editor::ToggleArg(name)),
             Op::ToggleSection => Box::new(editor::ToggleSection),
+            Op::ToggleMark => Box::new(editor::ToggleMark),
+            Op::ClearMarks => Box::new(editor::ClearMarks),
             Op::MoveDown => Box::new(editor::MoveDown),
             Op::MoveUp => Box::new(editor::MoveUp),
             Op::MoveDownLine => Box::new(editor::MoveDownLine),
@@ -152,9 +382,22 @@ impl Op {
             Op::HalfPageUp => Box::new(editor::HalfPageUp),
             Op::HalfPageDown => Box::new(editor::HalfPageDown),
             Op::Checkout => Box::new(branch::Checkout),
+            Op::CheckoutLocalBranch => Box::new(branch::CheckoutLocalBranch),
             Op::CheckoutNewBranch => Box::new(branch::CheckoutNewBranch),
             Op::Spinoff => Box::new(branch::Spinoff),
+            Op::CreateBranch => Box::new(branch::CreateBranch),
+            Op::ResetBranch => Box::new(branch::ResetBranch),
             Op::Delete => Box::new(branch::Delete),
+            Op::BranchConfigDescription => Box::new(branch_config::BranchConfigDescription),
+            Op::BranchConfigUpstream => Box::new(branch_config::BranchConfigUpstream),
+            Op::BranchConfigUnsetUpstream => Box::new(branch_config::BranchConfigUnsetUpstream),
+            Op::BranchConfigRebase => Box::new(branch_config::BranchConfigRebase),
+            Op::BranchConfigPushRemote => Box::new(branch_config::BranchConfigPushRemote),
+            Op::Clean => Box::new(clean::Clean),
+            Op::IgnoreToplevel => Box::new(ignore::Ignore(ignore::IgnoreFile::Toplevel)),
+            Op::IgnoreSubdirectory => Box::new(ignore::Ignore(ignore::IgnoreFile::Subdirectory)),
+            Op::IgnorePrivate => Box::new(ignore::Ignore(ignore::IgnoreFile::Private)),
+            Op::IgnoreGlobal => Box::new(ignore::Ignore(ignore::IgnoreFile::Global)),
             Op::Rename => Box::new(branch::Rename),
             Op::Commit => Box::new(commit::Commit),
             Op::CommitAmend => Box::new(commit::CommitAmend),
@@ -186,8 +429,16 @@ impl Op {
             Op::CommitInstantFixup => Box::new(commit::CommitInstantFixup),
             Op::Discard => Box::new(discard::Discard),
             Op::LogOther => Box::new(log::LogOther),
+            Op::Reflog => Box::new(log::Reflog),
+            Op::LogFile => Box::new(log::LogFile),
             Op::RebaseAutosquash => Box::new(rebase::RebaseAutosquash),
             Op::RebaseInteractive => Box::new(rebase::RebaseInteractive),
+            Op::RebaseReword => Box::new(rebase::RebaseAt(TodoAction::Reword)),
+            Op::RebaseEdit => Box::new(rebase::RebaseAt(TodoAction::Edit)),
+            Op::RebaseDrop => Box::new(rebase::RebaseAt(TodoAction::Drop)),
+            Op::RebaseMoveUp => Box::new(rebase::RebaseMove(rebase::MoveDirection::Up)),
+            Op::RebaseMoveDown => Box::new(rebase::RebaseMove(rebase::MoveDirection::Down)),
+            Op::RebaseSplit => Box::new(rebase::RebaseSplit),
             Op::ResetSoft => Box::new(reset::ResetSoft),
             Op::ResetMixed => Box::new(reset::ResetMixed),
             Op::ResetHard => Box::new(reset::ResetHard),
@@ -199,10 +450,13 @@ impl Op {
             Op::CherryPick => Box::new(cherry_pick::CherryPick),
             Op::Show => Box::new(show::Show),
             Op::Stage => Box::new(stage::Stage),
+            Op::StageEdit => Box::new(stage::StageEdit),
             Op::Unstage => Box::new(unstage::Unstage),
             Op::Apply => Box::new(apply::Apply),
             Op::Reverse => Box::new(reverse::Reverse),
             Op::CopyHash => Box::new(copy_hash::CopyHash),
+            Op::Blame => Box::new(blame::Blame),
+            Op::BlameParent => Box::new(blame::BlameParent),
 
             Op::AddRemote => Box::new(remote::AddRemote),
             Op::RemoveRemote => Box::new(remote::RemoveRemote),
@@ -211,6 +465,55 @@ impl Op {
             Op::Merge => Box::new(merge::Merge),
             Op::MergeAbort => Box::new(merge::MergeAbort),
             Op::MergeContinue => Box::new(merge::MergeContinue),
+            Op::TakeOurs => Box::new(conflict::TakeOurs),
+            Op::TakeTheirs => Box::new(conflict::TakeTheirs),
+            Op::TakeBoth => Box::new(conflict::TakeBoth),
+            Op::Mergetool => Box::new(conflict::Mergetool),
+            Op::FormatPatch => Box::new(patch::FormatPatch),
+            Op::Am => Box::new(patch::Am),
+            Op::AmContinue => Box::new(patch::AmContinue),
+            Op::AmSkip => Box::new(patch::AmSkip),
+            Op::AmAbort => Box::new(patch::AmAbort),
+
+            Op::CreateTag => Box::new(tag::CreateTag),
+            Op::CreateAnnotatedTag => Box::new(tag::CreateAnnotatedTag),
+            Op::DeleteTag => Box::new(tag::DeleteTag),
+            Op::DeleteRemoteTag => Box::new(tag::DeleteRemoteTag),
+            Op::PushTag => Box::new(tag::PushTag),
+            Op::PushAllTags => Box::new(tag::PushAllTags),
+
+            Op::AddWorktree => Box::new(worktree::AddWorktree),
+            Op::ListWorktrees => Box::new(worktree::ListWorktrees),
+            Op::RemoveWorktree => Box::new(worktree::RemoveWorktree),
+            Op::PruneWorktrees => Box::new(worktree::PruneWorktrees),
+            Op::SwitchWorktree => Box::new(worktree::SwitchWorktree),
+
+            Op::InitSubmodules => Box::new(submodule::InitSubmodules),
+            Op::UpdateSubmodules => Box::new(submodule::UpdateSubmodules),
+            Op::SyncSubmodules => Box::new(submodule::SyncSubmodules),
+            Op::FetchSubmodules => Box::new(submodule::FetchSubmodules),
+            Op::DiffRange => Box::new(diff::DiffRange),
+            Op::DiffMergeBase => Box::new(diff::DiffMergeBase),
+            Op::DiffWorktree => Box::new(diff::DiffWorktree),
+            Op::DiffIndex => Box::new(diff::DiffIndex),
+            Op::DiffPath => Box::new(diff::DiffPath),
+            Op::BisectStart => Box::new(bisect::BisectStart),
+            Op::BisectGood => Box::new(bisect::BisectGood),
+            Op::BisectBad => Box::new(bisect::BisectBad),
+            Op::BisectSkip => Box::new(bisect::BisectSkip),
+            Op::BisectReset => Box::new(bisect::BisectReset),
+            Op::BisectRun => Box::new(bisect::BisectRun),
+            Op::RebaseTodoPick => Box::new(rebase_todo::SetTodoAction(TodoAction::Pick)),
+            Op::RebaseTodoReword => Box::new(rebase_todo::SetTodoAction(TodoAction::Reword)),
+            Op::RebaseTodoEdit => Box::new(rebase_todo::SetTodoAction(TodoAction::Edit)),
+            Op::RebaseTodoSquash => Box::new(rebase_todo::SetTodoAction(TodoAction::Squash)),
+            Op::RebaseTodoFixup => Box::new(rebase_todo::SetTodoAction(TodoAction::Fixup)),
+            Op::RebaseTodoDrop => Box::new(rebase_todo::DropTodoLine),
+            Op::RebaseTodoExec => Box::new(rebase_todo::InsertTodoExec),
+            Op::RebaseTodoMoveUp => Box::new(rebase_todo::MoveTodoLineUp),
+            Op::RebaseTodoMoveDown => Box::new(rebase_todo::MoveTodoLineDown),
+            Op::RebaseTodoConfirm => Box::new(rebase_todo::ConfirmRebaseTodo),
+            Op::RebaseTodoAbort => Box::new(rebase_todo::AbortRebaseTodo),
         }
     }
 }
@@ -219,20 +522,30 @@ impl Display for Menu {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_str(match self {
             Menu::Root => "Root",
+            Menu::Bisect => "Bisect",
             Menu::Branch => "Branch",
+            Menu::BranchConfig => "Configure branch",
+            Menu::Clean => "Clean",
             Menu::Commit => "Commit",
+            Menu::Diff => "Diff",
             Menu::Fetch => "Fetch",
             Menu::Help => "Help",
+            Menu::Ignore => "Ignore",
             Menu::Log => "Log",
             Menu::Merge => "Merge",
             Menu::Remote => "Remote",
+            Menu::Patch => "Patch",
             Menu::Pull => "Pull",
             Menu::Push => "Push",
             Menu::Rebase => "Rebase",
+            Menu::RebaseTodo => "Rebase todo",
             Menu::Reset => "Reset",
             Menu::Revert => "Revert",
             Menu::CherryPick => "Cherry-pick",
             Menu::Stash => "Stash",
+            Menu::Submodule => "Submodule",
+            Menu::Tag => "Tag",
+            Menu::Worktree => "Worktree",
         })
     }
 }
@@ -241,6 +554,40 @@ pub(crate) fn confirm(app: &mut App, term: &mut Term, prompt: &'static str) -> R
     app.confirm(term, prompt)
 }
 
+/// Shows a screen, e.g. of what is about to change, for as long as it takes to confirm.
+pub(crate) fn confirm_with_preview(
+    app: &mut App,
+    term: &mut Term,
+    preview: Screen,
+    prompt: &'static str,
+) -> Res<()> {
+    app.state.screens.push(preview);
+    let confirmed = confirm(app, term, prompt);
+    app.state.screens.pop();
+    confirmed
+}
+
 pub(crate) fn selected_rev(app: &App) -> Option<Rev> {
     app.selected_rev()
 }
+
+/// Picks a branch or tag, or any revision typed in.
+pub(crate) fn pick_rev(
+    app: &mut App,
+    term: &mut Term,
+    prompt: &'static str,
+    default: Rev,
+) -> Res<Option<String>> {
+    let result = app.pick(
+        term,
+        PickerState::with_refs(PickerParams {
+            prompt: prompt.into(),
+            refs: &git::branches_tags(&app.state.repo)?,
+            exclude_ref: None,
+            default: Some(default),
+            allow_custom_input: true,
+        }),
+    )?;
+
+    Ok(result.map(|data| data.display().to_string()))
+}
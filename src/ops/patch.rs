@@ -0,0 +1,180 @@
+use std::{
+    ffi::OsString,
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+    rc::Rc,
+};
+
+use crate::{
+    Res,
+    app::{App, PromptParams, State},
+    error::Error,
+    git,
+    item_data::{ItemData, Ref, Rev},
+    menu::arg::{Arg, any_string, positive_number},
+    term::Term,
+};
+
+use super::{Action, OpTrait, pick_rev, selected_rev};
+
+const FORMAT_PATCH_ARGS: [&str; 3] = ["--cover-letter", "--output-directory", "--reroll-count"];
+const AM_ARGS: [&str; 2] = ["--3way", "--signoff"];
+
+pub(crate) fn init_args() -> Vec<Arg> {
+    vec![
+        Arg::new_flag("--cover-letter", "Generate a cover letter", false),
+        Arg::new_arg("--output-directory", "Output directory", None, any_string),
+        Arg::new_arg("--reroll-count", "Version", None, positive_number),
+        Arg::new_flag("--3way", "Fall back on 3way merge", false),
+        Arg::new_flag("--signoff", "Add Signed-off-by lines", false),
+    ]
+}
+
+/// The active menu args meant for one of the commands in the menu.
+fn menu_args(app: &App, names: &[&str]) -> Vec<OsString> {
+    let Some(menu) = app.state.pending_menu.as_ref() else {
+        return vec![];
+    };
+
+    names
+        .iter()
+        .filter_map(|name| menu.args.get(*name))
+        .filter(|arg| arg.is_active())
+        .map(|arg| arg.get_cli_token().into())
+        .collect()
+}
+
+pub(crate) struct FormatPatch;
+impl OpTrait for FormatPatch {
+    fn get_action(&self, target: &ItemData) -> Option<Action> {
+        let rev = target.rev();
+        Some(Rc::new(move |app: &mut App, term: &mut Term| {
+            let end = rev
+                .clone()
+                .or_else(|| selected_rev(app))
+                .map(|rev| rev.shorthand().to_string())
+                .unwrap_or_else(|| "HEAD".to_string());
+
+            let default = match git::remote::get_upstream_shortname(&app.state.repo)? {
+                Some(upstream) => Rev::Ref(Ref::Remote(upstream)),
+                None => Rev::Commit(format!("{end}~")),
+            };
+
+            let Some(base) = pick_rev(app, term, "Format patches since", default)? else {
+                return Ok(());
+            };
+
+            let mut cmd = Command::new("git");
+            cmd.arg("format-patch");
+            cmd.args(menu_args(app, &FORMAT_PATCH_ARGS));
+            cmd.arg(format!("{base}..{end}"));
+            app.run_cmd(term, &[], cmd)
+        }))
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "Format patches".into()
+    }
+}
+
+pub(crate) struct Am;
+impl OpTrait for Am {
+    fn get_action(&self, _target: &ItemData) -> Option<Action> {
+        Some(Rc::new(|app: &mut App, term: &mut Term| {
+            let path = app.prompt(
+                term,
+                &PromptParams {
+                    prompt: "Apply patches from (mbox or directory)",
+                    ..Default::default()
+                },
+            )?;
+
+            if path.is_empty() {
+                return Err(Error::PatchPathRequired);
+            }
+
+            let workdir = app.state.repo.workdir().ok_or(Error::NoRepoWorkdir)?;
+            let patches = patch_files(&workdir.join(&path), &path)?;
+
+            let mut cmd = Command::new("git");
+            cmd.arg("am");
+            cmd.args(menu_args(app, &AM_ARGS));
+            cmd.args(patches);
+            app.run_cmd_interactive(term, cmd)
+        }))
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "Apply patches".into()
+    }
+}
+
+/// `git am` only reads Maildirs, so a directory of patches is passed as its `.patch` files in order.
+fn patch_files(full_path: &Path, path: &str) -> Res<Vec<PathBuf>> {
+    if !full_path.is_dir() {
+        return Ok(vec![PathBuf::from(path)]);
+    }
+
+    let mut patches = fs::read_dir(full_path)
+        .map_err(Error::ReadPatchDir)?
+        .map(|entry| entry.map(|entry| entry.file_name()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(Error::ReadPatchDir)?
+        .into_iter()
+        .filter(|name| {
+            Path::new(name)
+                .extension()
+                .is_some_and(|ext| ext == "patch")
+        })
+        .map(|name| Path::new(path).join(name))
+        .collect::<Vec<_>>();
+
+    patches.sort();
+    Ok(patches)
+}
+
+pub(crate) struct AmContinue;
+impl OpTrait for AmContinue {
+    fn get_action(&self, _target: &ItemData) -> Option<Action> {
+        Some(Rc::new(|app: &mut App, term: &mut Term| {
+            let mut cmd = Command::new("git");
+            cmd.args(["am", "--continue"]);
+            app.run_cmd_interactive(term, cmd)
+        }))
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "Continue".into()
+    }
+}
+
+pub(crate) struct AmSkip;
+impl OpTrait for AmSkip {
+    fn get_action(&self, _target: &ItemData) -> Option<Action> {
+        Some(Rc::new(|app: &mut App, term: &mut Term| {
+            let mut cmd = Command::new("git");
+            cmd.args(["am", "--skip"]);
+            app.run_cmd_interactive(term, cmd)
+        }))
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "Skip".into()
+    }
+}
+
+pub(crate) struct AmAbort;
+impl OpTrait for AmAbort {
+    fn get_action(&self, _target: &ItemData) -> Option<Action> {
+        Some(Rc::new(|app: &mut App, term: &mut Term| {
+            let mut cmd = Command::new("git");
+            cmd.args(["am", "--abort"]);
+            app.run_cmd_interactive(term, cmd)
+        }))
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "Abort".into()
+    }
+}
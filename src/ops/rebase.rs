@@ -1,17 +1,23 @@
-use super::{Action, OpTrait};
+use super::{Action, OpTrait, confirm};
 use crate::{
     Res,
     app::{App, State},
-    git,
+    error::Error,
+    git::{
+        self,
+        rebase_todo::{self, TodoAction},
+    },
     item_data::{ItemData, Ref},
     menu::arg::Arg,
     picker::{PickerParams, PickerState},
+    screen,
     term::Term,
 };
 use std::{
     ffi::{OsStr, OsString},
     process::Command,
     rc::Rc,
+    sync::Arc,
 };
 
 pub(crate) fn init_args() -> Vec<Arg> {
@@ -34,6 +40,8 @@ pub(crate) struct RebaseContinue;
 impl OpTrait for RebaseContinue {
     fn get_action(&self, _target: &ItemData) -> Option<Action> {
         Some(Rc::new(|app: &mut App, term: &mut Term| {
+            git::finish_split(&app.state.repo)?;
+
             let mut cmd = Command::new("git");
             cmd.args(["rebase", "--continue"]);
             app.run_cmd_interactive(term, cmd)?;
@@ -126,7 +134,15 @@ impl OpTrait for RebaseInteractive {
                 let rev = OsString::from(oid);
                 Rc::new(move |app: &mut App, term: &mut Term| {
                     let args = app.state.pending_menu.as_ref().unwrap().args();
-                    app.run_cmd_interactive(term, rebase_interactive_cmd(&args, &rev))
+                    app.run_cmd(term, &[], rebase_interactive_cmd(&args, &rev))?;
+
+                    let size = app.screen().size;
+                    app.state.screens.push(screen::rebase_todo::create(
+                        Arc::clone(&app.state.config),
+                        Rc::clone(&app.state.repo),
+                        size,
+                    )?);
+                    Ok(())
                 })
             }
             _ => return None,
@@ -143,11 +159,14 @@ impl OpTrait for RebaseInteractive {
     }
 }
 
+/// Starts the rebase stopped at a `break` before the first line,
+/// so the todo can be edited in Gitu and resumed with `git rebase --continue`.
 fn rebase_interactive_cmd(args: &[OsString], rev: &OsStr) -> Command {
     let mut cmd = Command::new("git");
     cmd.args(["rebase", "-i"]);
     cmd.args(args);
     cmd.arg(parent(rev));
+    cmd.env("GIT_SEQUENCE_EDITOR", rebase_todo::BREAK_SEQUENCE_EDITOR);
     cmd
 }
 
@@ -197,3 +216,294 @@ fn rebase_autosquash_cmd(args: &[OsString], rev: &OsStr) -> Command {
     cmd.arg(rev);
     cmd
 }
+
+/// Rewords, edits or drops a single commit, rebasing everything after it.
+pub(crate) struct RebaseAt(pub TodoAction);
+impl OpTrait for RebaseAt {
+    fn get_action(&self, target: &ItemData) -> Option<Action> {
+        let ItemData::Commit { oid, .. } = target else {
+            return None;
+        };
+
+        let oid = oid.clone();
+        let action = self.0;
+
+        Some(Rc::new(move |app: &mut App, term: &mut Term| {
+            let repo = Rc::clone(&app.state.repo);
+            let history = first_parent_history(&repo)?;
+            check_no_merges(&history[..=position_on_branch(&history, &oid)?])?;
+
+            if action == TodoAction::Drop {
+                confirm(app, term, "Really drop this commit? (y or n)")?;
+            }
+
+            let cmd = rebase_at_cmd(app, &oid, action);
+            let result = match action {
+                TodoAction::Reword => app.run_cmd_interactive(term, cmd),
+                _ => app.run_cmd(term, &[], cmd),
+            };
+
+            if result.is_err() && stopped_on_conflicts(app)? {
+                return Err(Error::RebaseStoppedOnConflicts);
+            }
+
+            result
+        }))
+    }
+
+    fn is_target_op(&self) -> bool {
+        true
+    }
+
+    fn display(&self, _state: &State) -> String {
+        match self.0 {
+            TodoAction::Reword => "reword a commit",
+            TodoAction::Edit => "edit a commit",
+            TodoAction::Drop => "drop a commit",
+            _ => "rebase a commit",
+        }
+        .into()
+    }
+}
+
+fn rebase_at_cmd(app: &App, oid: &str, action: TodoAction) -> Command {
+    let mut cmd = Command::new("git");
+    cmd.args([
+        "rebase",
+        "-i",
+        "--autostash",
+        "--keep-empty",
+        "--no-autosquash",
+    ]);
+
+    if app.state.repo.revparse_single(&format!("{oid}^")).is_ok() {
+        cmd.arg(parent(OsStr::new(oid)));
+    } else {
+        cmd.arg("--root");
+    }
+
+    cmd.env(
+        "GIT_SEQUENCE_EDITOR",
+        rebase_todo::first_line_sequence_editor(action),
+    );
+    cmd
+}
+
+fn stopped_on_conflicts(app: &App) -> Res<bool> {
+    if git::rebase_status(&app.state.repo)?.is_none() {
+        return Ok(false);
+    }
+
+    let workdir = app.state.repo.workdir().ok_or(Error::NoRepoWorkdir)?;
+    Ok(git::status(workdir)?
+        .files
+        .iter()
+        .any(|file| file.conflict().is_some()))
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum MoveDirection {
+    /// Towards newer commits, swapping with the child
+    Up,
+    /// Towards older commits, swapping with the parent
+    Down,
+}
+
+/// Swaps a commit with its neighbour on the current branch, keeping the menu open to move it again.
+pub(crate) struct RebaseMove(pub MoveDirection);
+impl OpTrait for RebaseMove {
+    fn get_action(&self, target: &ItemData) -> Option<Action> {
+        let ItemData::Commit { oid, .. } = target else {
+            return None;
+        };
+
+        let oid = oid.clone();
+        let direction = self.0;
+
+        Some(Rc::new(move |app: &mut App, term: &mut Term| {
+            move_commit(app, term, &oid, direction)
+        }))
+    }
+
+    fn is_target_op(&self) -> bool {
+        true
+    }
+
+    fn display(&self, _state: &State) -> String {
+        match self.0 {
+            MoveDirection::Up => "move commit up",
+            MoveDirection::Down => "move commit down",
+        }
+        .into()
+    }
+}
+
+fn move_commit(app: &mut App, term: &mut Term, oid: &str, direction: MoveDirection) -> Res<()> {
+    let repo = Rc::clone(&app.state.repo);
+    let history = first_parent_history(&repo)?;
+    let index = position_on_branch(&history, oid)?;
+
+    // The two commits to swap are at `older - 1` and `older`, counting from HEAD
+    let (older, moved_to) = match direction {
+        MoveDirection::Up => (index, index.checked_sub(1)),
+        MoveDirection::Down => (index + 1, Some(index + 1)),
+    };
+
+    let (Some(moved_to), Some(older_commit)) = (moved_to, history.get(older)) else {
+        return Err(Error::NoCommitToSwapWith);
+    };
+
+    check_no_merges(&history[..=older])?;
+
+    if is_pushed(&repo, older_commit.id())? {
+        return Err(Error::MoveCommitPushed);
+    }
+
+    let cmd = rebase_move_cmd(older_commit);
+    let result = app.run_cmd(term, &[], cmd);
+    if result.is_err() && stopped_on_conflicts(app)? {
+        return Err(Error::RebaseStoppedOnConflicts);
+    }
+    result?;
+
+    if let Some(moved) = first_parent_history(&repo)?.get(moved_to) {
+        let moved = moved.id().to_string();
+        app.screen_mut()
+            .select_item(|data| matches!(data, ItemData::Commit { oid, .. } if *oid == moved));
+    }
+
+    app.inhibit_close_menu();
+    Ok(())
+}
+
+/// Where a commit is in `history`, which it needs to be in to be rewritten by rebasing HEAD.
+fn position_on_branch(history: &[git2::Commit], oid: &str) -> Res<usize> {
+    history
+        .iter()
+        .position(|commit| commit.id().to_string() == oid)
+        .ok_or(Error::CommitNotOnBranch)
+}
+
+/// Refuses to rebase commits that include merges, as those would get flattened.
+fn check_no_merges(commits: &[git2::Commit]) -> Res<()> {
+    if commits.iter().any(|commit| commit.parent_count() > 1) {
+        return Err(Error::MoveCommitAcrossMerge);
+    }
+
+    Ok(())
+}
+
+/// The commits reachable from HEAD by following first parents, newest first.
+fn first_parent_history(repo: &git2::Repository) -> Res<Vec<git2::Commit<'_>>> {
+    let mut revwalk = repo.revwalk().map_err(Error::ReadLog)?;
+    revwalk.push_head().map_err(Error::ReadLog)?;
+    revwalk.simplify_first_parent().map_err(Error::ReadLog)?;
+
+    revwalk
+        .map(|oid| {
+            oid.and_then(|oid| repo.find_commit(oid))
+                .map_err(Error::ReadLog)
+        })
+        .collect()
+}
+
+/// Whether the upstream of the current branch contains the commit.
+fn is_pushed(repo: &git2::Repository, oid: git2::Oid) -> Res<bool> {
+    let upstream = match git::get_current_branch(repo) {
+        Ok(branch) => git::remote::get_branch_upstream(&branch)?,
+        Err(Error::NotOnBranch) => None,
+        Err(e) => return Err(e),
+    };
+
+    let Some(upstream_oid) = upstream.and_then(|upstream| upstream.get().target()) else {
+        return Ok(false);
+    };
+
+    Ok(upstream_oid == oid
+        || repo
+            .graph_descendant_of(upstream_oid, oid)
+            .map_err(Error::ReadLog)?)
+}
+
+fn rebase_move_cmd(older: &git2::Commit) -> Command {
+    let mut cmd = Command::new("git");
+    cmd.args([
+        "rebase",
+        "-i",
+        "--autostash",
+        "--keep-empty",
+        "--no-autosquash",
+    ]);
+
+    match older.parent_id(0) {
+        Ok(parent) => cmd.arg(parent.to_string()),
+        Err(_) => cmd.arg("--root"),
+    };
+
+    cmd.env("GIT_SEQUENCE_EDITOR", rebase_todo::SWAP_SEQUENCE_EDITOR);
+    cmd
+}
+
+/// Rewinds to a commit with its changes unstaged, so they can be staged and committed in parts.
+/// The rebase continues by itself once nothing is left, see [`continue_split`].
+pub(crate) struct RebaseSplit;
+impl OpTrait for RebaseSplit {
+    fn get_action(&self, target: &ItemData) -> Option<Action> {
+        let ItemData::Commit { oid, .. } = target else {
+            return None;
+        };
+
+        let oid = oid.clone();
+
+        Some(Rc::new(move |app: &mut App, term: &mut Term| {
+            let repo = Rc::clone(&app.state.repo);
+            let history = first_parent_history(&repo)?;
+            let index = position_on_branch(&history, &oid)?;
+            check_no_merges(&history[..=index])?;
+
+            let base = history[index]
+                .parent_id(0)
+                .map_err(|_| Error::SplitRootCommit)?;
+
+            let cmd = rebase_at_cmd(app, &oid, TodoAction::Edit);
+            let result = app.run_cmd(term, &[], cmd);
+            if result.is_err() && stopped_on_conflicts(app)? {
+                return Err(Error::RebaseStoppedOnConflicts);
+            }
+            result?;
+
+            git::start_split(&repo, &oid, &base.to_string())?;
+
+            let mut cmd = Command::new("git");
+            cmd.args(["reset", "HEAD^"]);
+            app.run_cmd(term, &[], cmd)
+        }))
+    }
+
+    fn is_target_op(&self) -> bool {
+        true
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "split a commit".into()
+    }
+}
+
+/// Continues the rebase once the commits created add up to the commit being split.
+/// Called after committing and on refresh.
+pub(crate) fn continue_split(app: &mut App, term: &mut Term) -> Res<()> {
+    if !git::split_status(&app.state.repo)?.is_some_and(|split| split.done) {
+        return Ok(());
+    }
+
+    git::finish_split(&app.state.repo)?;
+
+    let mut cmd = Command::new("git");
+    cmd.args(["rebase", "--continue"]);
+    let result = app.run_cmd(term, &[], cmd);
+    if result.is_err() && stopped_on_conflicts(app)? {
+        return Err(Error::RebaseStoppedOnConflicts);
+    }
+
+    result
+}
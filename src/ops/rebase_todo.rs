@@ -0,0 +1,221 @@
+use super::{Action, OpTrait};
+use crate::{
+    Res,
+    app::{App, PromptParams, State},
+    error::Error,
+    git::rebase_todo::{self, TodoAction, TodoLine},
+    item_data::ItemData,
+    screen::NavMode,
+    term::Term,
+};
+use std::{process::Command, rc::Rc};
+
+pub(crate) struct SetTodoAction(pub TodoAction);
+impl OpTrait for SetTodoAction {
+    fn get_action(&self, target: &ItemData) -> Option<Action> {
+        let ItemData::RebaseTodoLine {
+            index,
+            line: TodoLine::Commit { .. },
+        } = target
+        else {
+            return None;
+        };
+
+        let index = *index;
+        let new_action = self.0;
+
+        Some(Rc::new(move |app: &mut App, _term: &mut Term| {
+            edit_todo(app, |lines| {
+                if let Some(TodoLine::Commit { action, .. }) = lines.get_mut(index) {
+                    *action = new_action;
+                }
+            })
+        }))
+    }
+
+    fn is_target_op(&self) -> bool {
+        true
+    }
+
+    fn display(&self, _state: &State) -> String {
+        self.0.to_string()
+    }
+}
+
+/// Drops a commit, or removes any other kind of line from the todo.
+pub(crate) struct DropTodoLine;
+impl OpTrait for DropTodoLine {
+    fn get_action(&self, target: &ItemData) -> Option<Action> {
+        let ItemData::RebaseTodoLine { index, line } = target else {
+            return None;
+        };
+
+        let index = *index;
+        let is_commit = matches!(line, TodoLine::Commit { .. });
+
+        Some(Rc::new(move |app: &mut App, _term: &mut Term| {
+            edit_todo(app, |lines| {
+                if is_commit {
+                    if let Some(TodoLine::Commit { action, .. }) = lines.get_mut(index) {
+                        *action = TodoAction::Drop;
+                    }
+                } else if index < lines.len() {
+                    lines.remove(index);
+                }
+            })
+        }))
+    }
+
+    fn is_target_op(&self) -> bool {
+        true
+    }
+
+    fn display(&self, _state: &State) -> String {
+        TodoAction::Drop.to_string()
+    }
+}
+
+pub(crate) struct InsertTodoExec;
+impl OpTrait for InsertTodoExec {
+    fn get_action(&self, target: &ItemData) -> Option<Action> {
+        let ItemData::RebaseTodoLine { index, .. } = target else {
+            return None;
+        };
+
+        let index = *index;
+
+        Some(Rc::new(move |app: &mut App, term: &mut Term| {
+            let command = app.prompt(
+                term,
+                &PromptParams {
+                    prompt: "Exec command",
+                    ..Default::default()
+                },
+            )?;
+
+            if command.is_empty() {
+                return Err(Error::ExecCommandRequired);
+            }
+
+            edit_todo(app, |lines| {
+                lines.insert((index + 1).min(lines.len()), TodoLine::Exec(command));
+            })?;
+
+            app.screen_mut().select_next(NavMode::Normal);
+            Ok(())
+        }))
+    }
+
+    fn is_target_op(&self) -> bool {
+        true
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "exec".into()
+    }
+}
+
+pub(crate) struct MoveTodoLineUp;
+impl OpTrait for MoveTodoLineUp {
+    fn get_action(&self, target: &ItemData) -> Option<Action> {
+        let ItemData::RebaseTodoLine { index, .. } = target else {
+            return None;
+        };
+
+        let index = *index;
+
+        Some(Rc::new(move |app: &mut App, _term: &mut Term| {
+            if index == 0 {
+                return Ok(());
+            }
+
+            edit_todo(app, |lines| lines.swap(index - 1, index))?;
+            app.screen_mut().select_previous(NavMode::Normal);
+            Ok(())
+        }))
+    }
+
+    fn is_target_op(&self) -> bool {
+        true
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "Move up".into()
+    }
+}
+
+pub(crate) struct MoveTodoLineDown;
+impl OpTrait for MoveTodoLineDown {
+    fn get_action(&self, target: &ItemData) -> Option<Action> {
+        let ItemData::RebaseTodoLine { index, .. } = target else {
+            return None;
+        };
+
+        let index = *index;
+
+        Some(Rc::new(move |app: &mut App, _term: &mut Term| {
+            let mut moved = false;
+            edit_todo(app, |lines| {
+                if index + 1 < lines.len() {
+                    lines.swap(index, index + 1);
+                    moved = true;
+                }
+            })?;
+
+            if moved {
+                app.screen_mut().select_next(NavMode::Normal);
+            }
+
+            Ok(())
+        }))
+    }
+
+    fn is_target_op(&self) -> bool {
+        true
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "Move down".into()
+    }
+}
+
+pub(crate) struct ConfirmRebaseTodo;
+impl OpTrait for ConfirmRebaseTodo {
+    fn get_action(&self, _target: &ItemData) -> Option<Action> {
+        Some(Rc::new(|app: &mut App, term: &mut Term| {
+            app.state.screens.pop();
+
+            let mut cmd = Command::new("git");
+            cmd.args(["rebase", "--continue"]);
+            app.run_cmd_interactive(term, cmd)
+        }))
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "Confirm".into()
+    }
+}
+
+pub(crate) struct AbortRebaseTodo;
+impl OpTrait for AbortRebaseTodo {
+    fn get_action(&self, _target: &ItemData) -> Option<Action> {
+        Some(Rc::new(|app: &mut App, term: &mut Term| {
+            app.state.screens.pop();
+
+            let mut cmd = Command::new("git");
+            cmd.args(["rebase", "--abort"]);
+            app.run_cmd(term, &[], cmd)
+        }))
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "Abort".into()
+    }
+}
+
+fn edit_todo(app: &mut App, edit: impl FnOnce(&mut Vec<TodoLine>)) -> Res<()> {
+    let mut lines = rebase_todo::read(&app.state.repo)?;
+    edit(&mut lines);
+    rebase_todo::write(&app.state.repo, &lines)?;
+    app.screen_mut().update()
+}
@@ -52,7 +52,7 @@ fn reverse_patch(patch: String) -> Action {
 
 fn reverse_line(diff: &Rc<Diff>, file_i: usize, hunk_i: usize, line_i: usize) -> Action {
     let patch = diff
-        .format_line_patch(file_i, hunk_i, line_i..(line_i + 1), PatchMode::Reverse)
+        .format_line_patch(file_i, hunk_i, &[line_i], PatchMode::Reverse)
         .into_bytes();
 
     Rc::new(move |app: &mut App, term: &mut Term| {
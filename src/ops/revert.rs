@@ -70,20 +70,33 @@ impl OpTrait for RevertCommit {
                 },
             )?;
 
-            revert_commit(app, term, &commit)?;
+            revert_commit(app, term, &[&commit])?;
             Ok(())
         }))
     }
 
+    fn get_marked_action(&self, targets: &[&ItemData]) -> Option<Action> {
+        let oids = super::commit_oids(targets)?;
+
+        Some(Rc::new(move |app: &mut App, term: &mut Term| {
+            let oids = oids.iter().map(String::as_str).collect::<Vec<_>>();
+            revert_commit(app, term, &oids)
+        }))
+    }
+
+    fn acts_on_marks(&self) -> bool {
+        true
+    }
+
     fn display(&self, _state: &State) -> String {
         "Revert commit(s)".into()
     }
 }
 
-fn revert_commit(app: &mut App, term: &mut Term, input: &str) -> Res<()> {
+fn revert_commit(app: &mut App, term: &mut Term, inputs: &[&str]) -> Res<()> {
     let mut cmd = Command::new("git");
     cmd.args(["revert"]);
     cmd.args(app.state.pending_menu.as_ref().unwrap().args());
-    cmd.arg(input);
+    cmd.args(inputs);
     app.run_cmd_interactive(term, cmd)
 }
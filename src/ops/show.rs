@@ -1,13 +1,19 @@
 use super::OpTrait;
 use crate::{
-    Action,
+    Action, Res,
     app::State,
     error::Error,
+    git::rebase_todo::TodoLine,
     item_data::{ItemData, Ref},
     screen,
 };
 use core::str;
-use std::{path::Path, process::Command, rc::Rc, sync::Arc};
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+    rc::Rc,
+    sync::Arc,
+};
 
 pub(crate) struct Show;
 impl OpTrait for Show {
@@ -22,9 +28,36 @@ impl OpTrait for Show {
                 kind: Ref::Head(oid),
                 ..
             } => goto_show_screen(oid.clone()),
+            ItemData::RebaseTodoLine {
+                line: TodoLine::Commit { oid, .. },
+                ..
+            } => goto_show_screen(oid.clone()),
+            ItemData::BlameChunk { blame, chunk_i }
+            | ItemData::BlameLine { blame, chunk_i, .. } => {
+                let chunk = &blame.chunks[*chunk_i];
+                if !chunk.is_committed() {
+                    return None;
+                }
+
+                goto_show_screen(chunk.oid.clone())
+            }
             ItemData::Untracked(u) => editor(u.as_path(), None),
+            ItemData::Unmerged { path, .. } => editor(path.as_path(), None),
+            ItemData::ConflictRegion { file, region_i } => editor(
+                Path::new(&file.path),
+                Some(file.regions[*region_i].line as u32),
+            ),
             ItemData::Delta { diff, file_i } => {
-                let file_path = &diff.file_diffs[*file_i].header.new_file;
+                let file_diff = &diff.file_diffs[*file_i];
+                if let Some(change) = file_diff.submodule_change(&diff.text) {
+                    return super::submodule::goto_submodule_log(
+                        PathBuf::from(&*file_diff.header.new_file.fmt(&diff.text)),
+                        change.old.map(|range| diff.text[range].to_string()),
+                        change.new.map(|range| diff.text[range].to_string()),
+                    );
+                }
+
+                let file_path = &file_diff.header.new_file;
                 let path: &str = &file_path.fmt(&diff.text);
                 editor(Path::new(path), None)
             }
@@ -41,6 +74,22 @@ impl OpTrait for Show {
                 )
             }
             ItemData::Stash { stash_ref, .. } => goto_show_stash_screen(stash_ref.clone()),
+            ItemData::Submodule {
+                path,
+                recorded,
+                checked_out,
+                ..
+            } => super::submodule::goto_submodule_log(
+                path.clone(),
+                recorded.clone(),
+                checked_out.clone(),
+            ),
+            ItemData::Worktree { path, .. } => {
+                let path = path.clone();
+                Some(Rc::new(move |app, _term| {
+                    super::worktree::switch_worktree(app, path.clone())
+                }))
+            }
             _ => None,
         }
     }
@@ -88,23 +137,26 @@ fn editor(file: &Path, maybe_line: Option<u32>) -> Option<Action> {
     let file = file.to_str().unwrap().to_string();
 
     Some(Rc::new(move |app, term| {
-        let configured_editor = EDITOR_VARS
-            .into_iter()
-            .find_map(|var| std::env::var(var).ok());
+        app.run_cmd_interactive(term, editor_command(&file, maybe_line)?)?;
+        app.update_screens()
+    }))
+}
 
-        let Some(editor) = configured_editor else {
-            return Err(Error::NoEditorSet);
-        };
+/// The command opening `file` (relative to the workdir) in the configured editor.
+pub(crate) fn editor_command(file: &str, maybe_line: Option<u32>) -> Res<Command> {
+    let configured_editor = EDITOR_VARS
+        .into_iter()
+        .find_map(|var| std::env::var(var).ok());
 
-        let cmd = if cfg!(windows) {
-            parse_editor_command_windows(&editor, &file, maybe_line)
-        } else {
-            parse_editor_command(&editor, &file, maybe_line)
-        };
+    let Some(editor) = configured_editor else {
+        return Err(Error::NoEditorSet);
+    };
 
-        app.run_cmd_interactive(term, cmd)?;
-        app.update_screens()
-    }))
+    Ok(if cfg!(windows) {
+        parse_editor_command_windows(&editor, file, maybe_line)
+    } else {
+        parse_editor_command(&editor, file, maybe_line)
+    })
 }
 
 fn parse_editor_command(editor: &str, file: &str, maybe_line: Option<u32>) -> Command {
@@ -1,13 +1,17 @@
-use super::OpTrait;
+use super::{MarkedTarget, OpTrait, show::editor_command};
 use crate::{
     Action,
     app::{App, State},
-    git::diff::{Diff, PatchMode},
+    error::Error,
+    git::{
+        self,
+        diff::{Diff, PatchMode},
+    },
     gitu_diff::Status,
     item_data::ItemData,
     term::Term,
 };
-use std::{ffi::OsString, process::Command, rc::Rc};
+use std::{ffi::OsString, fs, process::Command, rc::Rc};
 
 pub(crate) struct Stage;
 impl OpTrait for Stage {
@@ -16,6 +20,8 @@ impl OpTrait for Stage {
             ItemData::AllUnstaged(_) => stage_unstaged(),
             ItemData::AllUntracked(untracked) => stage_untracked(untracked.clone()),
             ItemData::Untracked(u) => stage_file(u.into()),
+            // Staging an unmerged path marks it resolved
+            ItemData::Unmerged { path, .. } => stage_file(path.into()),
             ItemData::Delta { diff, file_i } => {
                 let diff_header = &diff.file_diffs[*file_i].header;
                 let file_path = match diff_header.status {
@@ -35,7 +41,7 @@ impl OpTrait for Stage {
                 hunk_i,
                 line_i,
                 ..
-            } => stage_line(Rc::clone(diff), *file_i, *hunk_i, *line_i),
+            } => stage_lines(Rc::clone(diff), *file_i, *hunk_i, vec![*line_i]),
             _ => return None,
         };
 
@@ -46,6 +52,26 @@ impl OpTrait for Stage {
         true
     }
 
+    fn get_marked_action(&self, targets: &[&ItemData]) -> Option<Action> {
+        super::marked_targets(targets)?
+            .into_iter()
+            .map(|target| match target {
+                MarkedTarget::Item(target) => self.get_action(target),
+                MarkedTarget::Lines {
+                    diff,
+                    file_i,
+                    hunk_i,
+                    line_is,
+                } => Some(stage_lines(Rc::clone(diff), file_i, hunk_i, line_is)),
+            })
+            .collect::<Option<_>>()
+            .map(super::sequence)
+    }
+
+    fn acts_on_marks(&self) -> bool {
+        true
+    }
+
     fn display(&self, _state: &State) -> String {
         "Stage".into()
     }
@@ -92,15 +118,69 @@ fn stage_patch(diff: Rc<Diff>, file_i: usize, hunk_i: usize) -> Action {
     })
 }
 
-fn stage_line(diff: Rc<Diff>, file_i: usize, hunk_i: usize, line_i: usize) -> Action {
+fn stage_lines(diff: Rc<Diff>, file_i: usize, hunk_i: usize, line_is: Vec<usize>) -> Action {
     Rc::new(move |app, term| {
         let mut cmd = Command::new("git");
         cmd.args(["apply", "--cached", "--recount"]);
 
         let input = diff
-            .format_line_patch(file_i, hunk_i, line_i..(line_i + 1), PatchMode::Normal)
+            .format_line_patch(file_i, hunk_i, &line_is, PatchMode::Normal)
             .into_bytes();
 
         app.run_cmd(term, &input, cmd)
     })
 }
+
+pub(crate) struct StageEdit;
+impl OpTrait for StageEdit {
+    fn get_action(&self, target: &ItemData) -> Option<Action> {
+        match target {
+            ItemData::Hunk {
+                diff,
+                file_i,
+                hunk_i,
+            } => Some(stage_edited_patch(Rc::clone(diff), *file_i, *hunk_i)),
+            _ => None,
+        }
+    }
+
+    fn is_target_op(&self) -> bool {
+        true
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "Edit and stage".into()
+    }
+}
+
+/// Like `git add -e`, the hunk is edited in `.git/ADD_EDIT.patch` and only staged if it still applies.
+fn stage_edited_patch(diff: Rc<Diff>, file_i: usize, hunk_i: usize) -> Action {
+    Rc::new(move |app, term| {
+        let repo = Rc::clone(&app.state.repo);
+        let patch_path = repo.path().join("ADD_EDIT.patch");
+        fs::write(&patch_path, diff.format_hunk_patch(file_i, hunk_i))
+            .map_err(Error::WriteEditedPatch)?;
+
+        let workdir = repo.workdir().ok_or(Error::NoRepoWorkdir)?;
+        let relative_path = patch_path.strip_prefix(workdir).unwrap_or(&patch_path);
+        let edited = app
+            .run_cmd_interactive(
+                term,
+                editor_command(&relative_path.to_string_lossy(), None)?,
+            )
+            .and_then(|()| fs::read(&patch_path).map_err(Error::ReadEditedPatch));
+        let _ = fs::remove_file(&patch_path);
+        let patch = edited?;
+
+        if patch.iter().all(u8::is_ascii_whitespace) {
+            app.display_info("Edited patch is empty, nothing staged");
+            return Ok(());
+        }
+
+        git::check_cached_patch(&repo, &patch)?;
+
+        let mut cmd = Command::new("git");
+        cmd.args(["apply", "--cached", "--recount"]);
+        app.run_cmd(term, &patch, cmd)
+    })
+}
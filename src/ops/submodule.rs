@@ -0,0 +1,138 @@
+use super::{Action, OpTrait};
+use crate::{
+    app::{App, State},
+    error::Error,
+    git::submodule,
+    item_data::ItemData,
+    menu::arg::Arg,
+    screen,
+    term::Term,
+};
+use git2::Oid;
+use std::{path::PathBuf, process::Command, rc::Rc, sync::Arc};
+
+pub(crate) fn init_args() -> Vec<Arg> {
+    vec![Arg::new_flag("--recursive", "Recursive", false)]
+}
+
+pub(crate) struct InitSubmodules;
+impl OpTrait for InitSubmodules {
+    fn get_action(&self, target: &ItemData) -> Option<Action> {
+        let path = selected_submodule(target);
+
+        Some(Rc::new(move |app: &mut App, term: &mut Term| {
+            // `git submodule init` doesn't recurse, so the menu args don't apply
+            let mut cmd = Command::new("git");
+            cmd.args(["submodule", "init"]);
+            cmd.args(
+                path.iter()
+                    .flat_map(|path| ["--".as_ref(), path.as_os_str()]),
+            );
+            app.run_cmd(term, &[], cmd)
+        }))
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "Init".into()
+    }
+}
+
+pub(crate) struct UpdateSubmodules;
+impl OpTrait for UpdateSubmodules {
+    fn get_action(&self, target: &ItemData) -> Option<Action> {
+        let path = selected_submodule(target);
+
+        Some(Rc::new(move |app: &mut App, term: &mut Term| {
+            let mut cmd = Command::new("git");
+            cmd.args(["submodule", "update", "--init"]);
+            cmd.args(app.state.pending_menu.as_ref().unwrap().args());
+            cmd.args(
+                path.iter()
+                    .flat_map(|path| ["--".as_ref(), path.as_os_str()]),
+            );
+            app.run_cmd_async(term, &[], cmd)
+        }))
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "Update".into()
+    }
+}
+
+pub(crate) struct SyncSubmodules;
+impl OpTrait for SyncSubmodules {
+    fn get_action(&self, target: &ItemData) -> Option<Action> {
+        let path = selected_submodule(target);
+
+        Some(Rc::new(move |app: &mut App, term: &mut Term| {
+            let mut cmd = Command::new("git");
+            cmd.args(["submodule", "sync"]);
+            cmd.args(app.state.pending_menu.as_ref().unwrap().args());
+            cmd.args(
+                path.iter()
+                    .flat_map(|path| ["--".as_ref(), path.as_os_str()]),
+            );
+            app.run_cmd(term, &[], cmd)
+        }))
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "Sync".into()
+    }
+}
+
+pub(crate) struct FetchSubmodules;
+impl OpTrait for FetchSubmodules {
+    fn get_action(&self, _target: &ItemData) -> Option<Action> {
+        Some(Rc::new(|app: &mut App, term: &mut Term| {
+            let mut cmd = Command::new("git");
+            cmd.args(["submodule", "foreach"]);
+            cmd.args(app.state.pending_menu.as_ref().unwrap().args());
+            cmd.args(["git", "fetch"]);
+            app.run_cmd_async(term, &[], cmd)
+        }))
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "Fetch all".into()
+    }
+}
+
+fn selected_submodule(target: &ItemData) -> Option<PathBuf> {
+    match target {
+        ItemData::Submodule { path, .. } => Some(path.clone()),
+        _ => None,
+    }
+}
+
+/// Shows the log of the commits in submodule `path` between the `old` and `new` pointers.
+pub(crate) fn goto_submodule_log(
+    path: PathBuf,
+    old: Option<String>,
+    new: Option<String>,
+) -> Option<Action> {
+    Some(Rc::new(move |app: &mut App, _term: &mut Term| {
+        let Some(new) = &new else {
+            return Err(Error::SubmoduleNotCheckedOut);
+        };
+
+        let repo = submodule::open(&app.state.repo, &path)?;
+        let to = Oid::from_str(new).map_err(Error::ReadOid)?;
+        let from = old
+            .as_deref()
+            .map(Oid::from_str)
+            .transpose()
+            .map_err(Error::ReadOid)?;
+
+        let size = app.screen().size;
+        app.state.screens.push(screen::log::create_range(
+            Arc::clone(&app.state.config),
+            Rc::new(repo),
+            size,
+            from,
+            to,
+        )?);
+
+        Ok(())
+    }))
+}
@@ -0,0 +1,252 @@
+use super::{Action, OpTrait, confirm};
+use crate::{
+    Res,
+    app::{App, PromptParams, State},
+    error::Error,
+    git::{
+        self,
+        remote::{get_push_remote, get_upstream_remote},
+    },
+    item_data::{ItemData, Ref, Rev},
+    menu::arg::Arg,
+    picker::{PickerData, PickerItem, PickerParams, PickerState},
+    term::Term,
+};
+use std::{process::Command, rc::Rc};
+
+pub(crate) fn init_args() -> Vec<Arg> {
+    vec![Arg::new_flag("--force", "Force", false)]
+}
+
+pub(crate) struct CreateTag;
+impl OpTrait for CreateTag {
+    fn get_action(&self, target: &ItemData) -> Option<Action> {
+        create_tag_action(target.rev(), false)
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "Create tag".into()
+    }
+}
+
+pub(crate) struct CreateAnnotatedTag;
+impl OpTrait for CreateAnnotatedTag {
+    fn get_action(&self, target: &ItemData) -> Option<Action> {
+        create_tag_action(target.rev(), true)
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "Create annotated tag".into()
+    }
+}
+
+fn create_tag_action(default: Option<Rev>, annotate: bool) -> Option<Action> {
+    Some(Rc::new(move |app: &mut App, term: &mut Term| {
+        let tag_name = app.prompt(
+            term,
+            &PromptParams {
+                prompt: "Tag name",
+                ..Default::default()
+            },
+        )?;
+
+        if tag_name.is_empty() {
+            return Err(Error::TagNameRequired);
+        }
+
+        let default = match default.clone() {
+            Some(rev) => rev,
+            None => git::head(&app.state.repo)?,
+        };
+
+        let result = app.pick(
+            term,
+            PickerState::with_refs(PickerParams {
+                prompt: "Place tag on".into(),
+                refs: &git::branches_tags(&app.state.repo)?,
+                exclude_ref: None,
+                default: Some(default),
+                allow_custom_input: true,
+            }),
+        )?;
+
+        if let Some(data) = result {
+            create_tag(app, term, &tag_name, data.display(), annotate)?;
+        }
+
+        Ok(())
+    }))
+}
+
+fn create_tag(
+    app: &mut App,
+    term: &mut Term,
+    tag_name: &str,
+    rev: &str,
+    annotate: bool,
+) -> Res<()> {
+    let mut cmd = Command::new("git");
+    cmd.arg("tag");
+    cmd.args(app.state.pending_menu.as_ref().unwrap().args());
+
+    if annotate {
+        // Git opens $EDITOR for the message, just like `git commit` does
+        cmd.arg("--annotate");
+        cmd.args([tag_name, rev]);
+        app.run_cmd_interactive(term, cmd)
+    } else {
+        cmd.args([tag_name, rev]);
+        app.run_cmd(term, &[], cmd)
+    }
+}
+
+pub(crate) struct DeleteTag;
+impl OpTrait for DeleteTag {
+    fn get_action(&self, target: &ItemData) -> Option<Action> {
+        let default = target.rev();
+
+        Some(Rc::new(move |app: &mut App, term: &mut Term| {
+            let result = app.pick(
+                term,
+                PickerState::with_tags(PickerParams {
+                    prompt: "Delete tag".into(),
+                    refs: &git::tags(&app.state.repo)?,
+                    exclude_ref: None,
+                    default: default.clone(),
+                    allow_custom_input: false,
+                }),
+            )?;
+
+            if let Some(data) = result {
+                let mut cmd = Command::new("git");
+                cmd.args(["tag", "--delete", data.display()]);
+                app.run_cmd(term, &[], cmd)?;
+            }
+
+            Ok(())
+        }))
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "Delete tag".into()
+    }
+}
+
+pub(crate) struct DeleteRemoteTag;
+impl OpTrait for DeleteRemoteTag {
+    fn get_action(&self, target: &ItemData) -> Option<Action> {
+        let default = target.rev();
+
+        Some(Rc::new(move |app: &mut App, term: &mut Term| {
+            let result = app.pick(
+                term,
+                PickerState::with_tags(PickerParams {
+                    prompt: "Delete tag on remote".into(),
+                    refs: &git::tags(&app.state.repo)?,
+                    exclude_ref: None,
+                    default: default.clone(),
+                    allow_custom_input: true,
+                }),
+            )?;
+
+            let Some(data) = result else {
+                return Ok(());
+            };
+
+            let tag_name = data.display().to_string();
+            let Some(remote) = pick_remote(app, term, "Delete tag from remote")? else {
+                return Ok(());
+            };
+
+            confirm(app, term, "Really delete tag on remote? (y or n)")?;
+
+            let mut cmd = Command::new("git");
+            cmd.args(["push", &remote, &format!(":refs/tags/{tag_name}")]);
+            app.run_cmd_async(term, &[], cmd)
+        }))
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "Delete tag on remote".into()
+    }
+}
+
+pub(crate) struct PushTag;
+impl OpTrait for PushTag {
+    fn get_action(&self, target: &ItemData) -> Option<Action> {
+        let default = target.rev();
+
+        Some(Rc::new(move |app: &mut App, term: &mut Term| {
+            let result = app.pick(
+                term,
+                PickerState::with_tags(PickerParams {
+                    prompt: "Push tag".into(),
+                    refs: &git::tags(&app.state.repo)?,
+                    exclude_ref: None,
+                    default: default.clone(),
+                    allow_custom_input: false,
+                }),
+            )?;
+
+            let Some(data) = result else {
+                return Ok(());
+            };
+
+            let refspec = Ref::Tag(data.display().to_string()).to_full_refname();
+            let Some(remote) = pick_remote(app, term, "Push tag to")? else {
+                return Ok(());
+            };
+
+            let mut cmd = Command::new("git");
+            cmd.args(["push", &remote, &refspec]);
+            app.run_cmd_async(term, &[], cmd)
+        }))
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "Push tag".into()
+    }
+}
+
+pub(crate) struct PushAllTags;
+impl OpTrait for PushAllTags {
+    fn get_action(&self, _target: &ItemData) -> Option<Action> {
+        Some(Rc::new(|app: &mut App, term: &mut Term| {
+            let Some(remote) = pick_remote(app, term, "Push all tags to")? else {
+                return Ok(());
+            };
+
+            let mut cmd = Command::new("git");
+            cmd.args(["push", &remote, "--tags"]);
+            app.run_cmd_async(term, &[], cmd)
+        }))
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "Push all tags".into()
+    }
+}
+
+/// Lets the user pick a remote. The upstream remote (or push-remote) of the current branch is
+/// listed first.
+fn pick_remote(app: &mut App, term: &mut Term, prompt: &'static str) -> Res<Option<String>> {
+    let repo = Rc::clone(&app.state.repo);
+    let default = get_upstream_remote(&repo)
+        .ok()
+        .flatten()
+        .or_else(|| get_push_remote(&repo).ok().flatten());
+
+    let items = default
+        .iter()
+        .cloned()
+        .chain(
+            git::remote::remote_names(&repo)?
+                .into_iter()
+                .filter(|name| default.as_ref() != Some(name)),
+        )
+        .map(|name| PickerItem::new(name.clone(), PickerData::Item(name)))
+        .collect();
+
+    let result = app.pick(term, PickerState::new(prompt, items, true))?;
+    Ok(result.map(|data| data.display().to_string()))
+}
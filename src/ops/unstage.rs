@@ -1,4 +1,4 @@
-use super::OpTrait;
+use super::{MarkedTarget, OpTrait};
 use crate::{
     Action,
     app::{App, State},
@@ -35,13 +35,8 @@ impl OpTrait for Unstage {
                 line_i,
                 ..
             } => unstage_line(
-                diff.format_line_patch(
-                    *file_i,
-                    *hunk_i,
-                    *line_i..(*line_i + 1),
-                    PatchMode::Reverse,
-                )
-                .into_bytes(),
+                diff.format_line_patch(*file_i, *hunk_i, &[*line_i], PatchMode::Reverse)
+                    .into_bytes(),
             ),
             _ => return None,
         };
@@ -52,6 +47,29 @@ impl OpTrait for Unstage {
         true
     }
 
+    fn get_marked_action(&self, targets: &[&ItemData]) -> Option<Action> {
+        super::marked_targets(targets)?
+            .into_iter()
+            .map(|target| match target {
+                MarkedTarget::Item(target) => self.get_action(target),
+                MarkedTarget::Lines {
+                    diff,
+                    file_i,
+                    hunk_i,
+                    line_is,
+                } => Some(unstage_line(
+                    diff.format_line_patch(file_i, hunk_i, &line_is, PatchMode::Reverse)
+                        .into_bytes(),
+                )),
+            })
+            .collect::<Option<_>>()
+            .map(super::sequence)
+    }
+
+    fn acts_on_marks(&self) -> bool {
+        true
+    }
+
     fn display(&self, _state: &State) -> String {
         "Unstage".into()
     }
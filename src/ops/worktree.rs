@@ -0,0 +1,193 @@
+use super::{Action, OpTrait, confirm};
+use crate::{
+    Res,
+    app::{App, PromptParams, State},
+    error::Error,
+    git::{self, worktree},
+    item_data::ItemData,
+    menu::arg::Arg,
+    picker::{PickerData, PickerItem, PickerParams, PickerState},
+    term::Term,
+};
+use git2::Repository;
+use std::{path::PathBuf, process::Command, rc::Rc};
+
+pub(crate) fn init_args() -> Vec<Arg> {
+    vec![Arg::new_flag("--force", "Force", false)]
+}
+
+pub(crate) struct AddWorktree;
+impl OpTrait for AddWorktree {
+    fn get_action(&self, _target: &ItemData) -> Option<Action> {
+        Some(Rc::new(|app: &mut App, term: &mut Term| {
+            let result = app.pick(
+                term,
+                PickerState::with_branches(PickerParams {
+                    prompt: "Create worktree from branch".into(),
+                    refs: &git::branches(&app.state.repo, Some(git2::BranchType::Local))?,
+                    exclude_ref: git::head_ref(&app.state.repo)?,
+                    default: app.selected_rev(),
+                    allow_custom_input: true,
+                }),
+            )?;
+
+            let Some(data) = result else {
+                return Ok(());
+            };
+
+            let branch = data.display().to_string();
+            let path = app.prompt(
+                term,
+                &PromptParams {
+                    prompt: "Worktree path",
+                    create_default_value: Box::new(move |app| default_path(app, &branch)),
+                    ..Default::default()
+                },
+            )?;
+
+            if path.is_empty() {
+                return Err(Error::WorktreePathRequired);
+            }
+
+            let mut cmd = Command::new("git");
+            cmd.args(["worktree", "add"]);
+            cmd.args(app.state.pending_menu.as_ref().unwrap().args());
+            cmd.args([path.as_str(), data.display()]);
+            app.run_cmd(term, &[], cmd)
+        }))
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "Add worktree".into()
+    }
+}
+
+/// Suggests a directory next to the current worktree, named after the branch.
+fn default_path(app: &App, branch: &str) -> Option<String> {
+    let workdir = app.state.repo.workdir()?.canonicalize().ok()?;
+    let name = workdir.file_name()?.to_string_lossy();
+    Some(format!("../{}-{}", name, branch.replace('/', "-")))
+}
+
+pub(crate) struct ListWorktrees;
+impl OpTrait for ListWorktrees {
+    fn get_action(&self, _target: &ItemData) -> Option<Action> {
+        Some(Rc::new(|app: &mut App, term: &mut Term| {
+            let mut cmd = Command::new("git");
+            cmd.args(["worktree", "list"]);
+            app.run_cmd(term, &[], cmd)
+        }))
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "List worktrees".into()
+    }
+}
+
+pub(crate) struct RemoveWorktree;
+impl OpTrait for RemoveWorktree {
+    fn get_action(&self, target: &ItemData) -> Option<Action> {
+        let selected = selected_worktree(target);
+
+        Some(Rc::new(move |app: &mut App, term: &mut Term| {
+            let path = match selected.clone() {
+                Some(path) => path,
+                None => match pick_worktree(app, term, "Remove worktree")? {
+                    Some(path) => path,
+                    None => return Ok(()),
+                },
+            };
+
+            confirm(app, term, "Really remove worktree? (y or n)")?;
+
+            let workdir = app.state.repo.workdir().ok_or(Error::NoRepoWorkdir)?;
+            let mut cmd = Command::new("git");
+            cmd.args(["worktree", "remove"]);
+            cmd.args(app.state.pending_menu.as_ref().unwrap().args());
+            cmd.arg(worktree::display_path(&path, workdir));
+            app.run_cmd(term, &[], cmd)
+        }))
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "Remove worktree".into()
+    }
+}
+
+pub(crate) struct PruneWorktrees;
+impl OpTrait for PruneWorktrees {
+    fn get_action(&self, _target: &ItemData) -> Option<Action> {
+        Some(Rc::new(|app: &mut App, term: &mut Term| {
+            let mut cmd = Command::new("git");
+            cmd.args(["worktree", "prune", "--verbose"]);
+            app.run_cmd(term, &[], cmd)
+        }))
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "Prune worktrees".into()
+    }
+}
+
+pub(crate) struct SwitchWorktree;
+impl OpTrait for SwitchWorktree {
+    fn get_action(&self, target: &ItemData) -> Option<Action> {
+        let selected = selected_worktree(target);
+
+        Some(Rc::new(move |app: &mut App, term: &mut Term| {
+            let path = match selected.clone() {
+                Some(path) => path,
+                None => match pick_worktree(app, term, "Switch to worktree")? {
+                    Some(path) => path,
+                    None => return Ok(()),
+                },
+            };
+
+            switch_worktree(app, path)
+        }))
+    }
+
+    fn display(&self, _state: &State) -> String {
+        "Switch worktree".into()
+    }
+}
+
+pub(crate) fn switch_worktree(app: &mut App, path: PathBuf) -> Res<()> {
+    let repo = Repository::open(path).map_err(Error::OpenRepo)?;
+    app.reopen(repo)
+}
+
+fn selected_worktree(target: &ItemData) -> Option<PathBuf> {
+    match target {
+        ItemData::Worktree { path, .. } => Some(path.clone()),
+        _ => None,
+    }
+}
+
+fn pick_worktree(app: &mut App, term: &mut Term, prompt: &'static str) -> Res<Option<PathBuf>> {
+    let workdir = app
+        .state
+        .repo
+        .workdir()
+        .ok_or(Error::NoRepoWorkdir)?
+        .to_path_buf();
+
+    let worktrees = worktree::list_others(&app.state.repo)?;
+    if worktrees.is_empty() {
+        return Err(Error::NoOtherWorktrees);
+    }
+
+    let items = worktrees
+        .into_iter()
+        .map(|worktree| {
+            let display = worktree::display_path(&worktree.path, &workdir);
+            PickerItem::new(
+                display,
+                PickerData::Item(worktree.path.to_string_lossy().into_owned()),
+            )
+        })
+        .collect();
+
+    let result = app.pick(term, PickerState::new(prompt, items, false))?;
+    Ok(result.map(|data| PathBuf::from(data.display())))
+}
@@ -115,12 +115,36 @@ impl PickerState {
     /// Create a picker showing only local branches by shorthand name.
     /// The default branch (if any) is listed first. The exclude_ref is omitted.
     pub(crate) fn with_branches(params: PickerParams) -> Self {
+        Self::branch_picker(params, false)
+    }
+
+    /// Like `with_branches`, followed by remote branches that have no local branch of the
+    /// same name, for checking them out as a new local branch.
+    pub(crate) fn with_branches_to_checkout(params: PickerParams) -> Self {
+        Self::branch_picker(params, true)
+    }
+
+    fn branch_picker(params: PickerParams, include_remote_only: bool) -> Self {
         let default_name = params.default.as_ref().map(|r| r.shorthand().to_string());
         let exclude_name = params
             .exclude_ref
             .as_ref()
             .map(|r| r.shorthand().to_string());
 
+        let is_local = |name: &str| {
+            params
+                .refs
+                .iter()
+                .any(|r| matches!(r, Ref::Head(local) if local == name))
+        };
+
+        let is_remote_only = |r: &&Ref| match r {
+            Ref::Remote(name) if include_remote_only => name
+                .split_once('/')
+                .is_some_and(|(_, branch)| branch != "HEAD" && !is_local(branch)),
+            _ => false,
+        };
+
         let items = default_name
             .iter()
             .map(|name| PickerItem::new(name.clone(), PickerData::Item(name.clone())))
@@ -129,6 +153,39 @@ impl PickerState {
                     .refs
                     .iter()
                     .filter(|r| matches!(r, Ref::Head(_)))
+                    .chain(params.refs.iter().filter(is_remote_only))
+                    .filter(|r| exclude_name.as_deref().is_none_or(|e| e != r.shorthand()))
+                    .filter(|r| default_name.as_deref().is_none_or(|d| d != r.shorthand()))
+                    .map(|r| {
+                        let name = r.shorthand().to_string();
+                        PickerItem::new(name.clone(), PickerData::Item(name))
+                    }),
+            )
+            .collect();
+
+        Self::new(params.prompt, items, params.allow_custom_input)
+    }
+
+    /// Create a picker showing only tags by shorthand name.
+    /// The default tag (if any) is listed first. The exclude_ref is omitted.
+    pub(crate) fn with_tags(params: PickerParams) -> Self {
+        let default_name = match &params.default {
+            Some(Rev::Ref(Ref::Tag(name))) => Some(name.clone()),
+            _ => None,
+        };
+        let exclude_name = params
+            .exclude_ref
+            .as_ref()
+            .map(|r| r.shorthand().to_string());
+
+        let items = default_name
+            .iter()
+            .map(|name| PickerItem::new(name.clone(), PickerData::Item(name.clone())))
+            .chain(
+                params
+                    .refs
+                    .iter()
+                    .filter(|r| matches!(r, Ref::Tag(_)))
                     .filter(|r| exclude_name.as_deref().is_none_or(|e| e != r.shorthand()))
                     .filter(|r| default_name.as_deref().is_none_or(|d| d != r.shorthand()))
                     .map(|r| {
@@ -204,7 +261,7 @@ impl PickerState {
                 .collect();
 
             // Sort by score (higher is better)
-            matches.sort_by(|a, b| b.score.cmp(&a.score));
+            matches.sort_by_key(|m| std::cmp::Reverse(m.score));
 
             self.filtered_indices = matches.into_iter().map(|m| m.index).collect();
 
@@ -1315,4 +1372,40 @@ mod tests {
         // Default remote-tracking branch shown first as shorthand, then remaining
         assert_eq!(&items, &["origin/main", "main", "origin/feature"]);
     }
+
+    #[test]
+    fn test_with_branches_remote_only() {
+        let refs = &[
+            Ref::Head("main".to_string()),
+            Ref::Remote("origin/HEAD".to_string()),
+            Ref::Remote("origin/main".to_string()),
+            Ref::Remote("origin/feature".to_string()),
+            Ref::Tag("v1.0.0".to_string()),
+        ];
+
+        let params = || PickerParams {
+            prompt: "Select".into(),
+            refs,
+            exclude_ref: None,
+            default: None,
+            allow_custom_input: false,
+        };
+
+        let state = PickerState::with_branches(params());
+        let items: Vec<_> = state
+            .filtered_items()
+            .map(|(_, item)| item.display.as_ref())
+            .collect();
+
+        assert_eq!(&items, &["main"]);
+
+        let state = PickerState::with_branches_to_checkout(params());
+        let items: Vec<_> = state
+            .filtered_items()
+            .map(|(_, item)| item.display.as_ref())
+            .collect();
+
+        // Local branches, then remote branches without a local counterpart
+        assert_eq!(&items, &["main", "origin/feature"]);
+    }
 }
@@ -0,0 +1,57 @@
+use std::{rc::Rc, sync::Arc};
+
+use super::Screen;
+use crate::{
+    Res,
+    config::Config,
+    git,
+    item_data::ItemData,
+    items::{Item, hash},
+};
+use git2::Repository;
+use ratatui::layout::Size;
+
+/// Blames `path` at `rev`, or the file in the worktree if no revision is given.
+pub(crate) fn create(
+    config: Arc<Config>,
+    repo: Rc<Repository>,
+    size: Size,
+    path: String,
+    rev: Option<String>,
+) -> Res<Screen> {
+    Screen::new(
+        Arc::clone(&config),
+        size,
+        Box::new(move || {
+            let blame = Rc::new(git::blame::blame(&repo, &path, rev.as_deref())?);
+
+            Ok((0..blame.chunks.len())
+                .flat_map(|chunk_i| {
+                    let chunk = &blame.chunks[chunk_i];
+
+                    [Item {
+                        id: hash((&chunk.oid, chunk.lines.start)),
+                        depth: 0,
+                        data: ItemData::BlameChunk {
+                            blame: Rc::clone(&blame),
+                            chunk_i,
+                        },
+                        ..Default::default()
+                    }]
+                    .into_iter()
+                    .chain(chunk.lines.clone().map(|line_i| Item {
+                        id: blame.id,
+                        depth: 1,
+                        data: ItemData::BlameLine {
+                            blame: Rc::clone(&blame),
+                            chunk_i,
+                            line_i,
+                        },
+                        ..Default::default()
+                    }))
+                    .collect::<Vec<_>>()
+                })
+                .collect())
+        }),
+    )
+}
@@ -0,0 +1,33 @@
+use super::Screen;
+use crate::{
+    Res,
+    config::Config,
+    item_data::ItemData,
+    items::{Item, hash},
+};
+use ratatui::layout::Size;
+use std::{iter, path::PathBuf, sync::Arc};
+
+/// Lists what `git clean` is about to remove.
+pub(crate) fn create(config: Arc<Config>, size: Size, paths: Vec<String>) -> Res<Screen> {
+    Screen::new(
+        Arc::clone(&config),
+        size,
+        Box::new(move || {
+            let header = Item {
+                id: hash("clean"),
+                data: ItemData::AllUntracked(paths.iter().map(PathBuf::from).collect()),
+                ..Default::default()
+            };
+
+            Ok(iter::once(header)
+                .chain(paths.iter().map(|path| Item {
+                    id: hash(["clean", path]),
+                    depth: 1,
+                    data: ItemData::Untracked(PathBuf::from(path)),
+                    ..Default::default()
+                }))
+                .collect())
+        }),
+    )
+}
@@ -0,0 +1,69 @@
+use std::{iter, rc::Rc, sync::Arc};
+
+use super::Screen;
+use crate::{
+    Res,
+    config::Config,
+    git::{self, diff::DiffType},
+    item_data::{ItemData, SectionHeader},
+    items::{self, Item, hash},
+};
+use git2::Repository;
+use ratatui::layout::Size;
+
+pub(crate) fn create(
+    config: Arc<Config>,
+    repo: Rc<Repository>,
+    size: Size,
+    diff_type: DiffType,
+) -> Res<Screen> {
+    Screen::new(
+        Arc::clone(&config),
+        size,
+        Box::new(move || {
+            let title = title(&diff_type);
+            let diff = Rc::new(git::diff(&repo, diff_type.clone())?);
+
+            let header = Item {
+                id: hash(["diff", &title]),
+                depth: 0,
+                unselectable: true,
+                data: ItemData::Header(SectionHeader::Diff(title)),
+                ..Default::default()
+            };
+
+            if diff.file_diffs.is_empty() {
+                return Ok(vec![
+                    header,
+                    Item {
+                        id: hash("no_changes"),
+                        depth: 1,
+                        unselectable: true,
+                        data: ItemData::Raw("No changes".into()),
+                        ..Default::default()
+                    },
+                ]);
+            }
+
+            Ok(iter::once(header)
+                .chain([items::blank_line()])
+                .chain(items::create_diff_items(&diff, 0, false))
+                .collect())
+        }),
+    )
+}
+
+fn title(diff_type: &DiffType) -> String {
+    match diff_type {
+        DiffType::WorkdirToIndex => "Unstaged changes".into(),
+        DiffType::IndexToTree => "Staged changes".into(),
+        DiffType::TreeToTree => "Changes".into(),
+        DiffType::Range { from, to } => format!("Changes from {from} to {to}"),
+        DiffType::MergeBase { from, to } => {
+            format!("Changes on {to} since it forked from {from}")
+        }
+        DiffType::WorkdirToCommit(rev) => format!("Changes in worktree since {rev}"),
+        DiffType::IndexToCommit(rev) => format!("Changes in index since {rev}"),
+        DiffType::Path { from, to, path } => format!("Changes to {path} from {from} to {to}"),
+    }
+}
@@ -0,0 +1,38 @@
+use super::Screen;
+use crate::{
+    Res,
+    config::Config,
+    item_data::{ItemData, SectionHeader},
+    items::{Item, hash},
+};
+use ratatui::layout::Size;
+use std::{iter, sync::Arc};
+
+/// Lists the entries about to be added to an ignore file.
+pub(crate) fn create(
+    config: Arc<Config>,
+    size: Size,
+    file: String,
+    patterns: Vec<String>,
+) -> Res<Screen> {
+    Screen::new(
+        Arc::clone(&config),
+        size,
+        Box::new(move || {
+            let header = Item {
+                id: hash("ignore"),
+                data: ItemData::Header(SectionHeader::Ignore(file.clone())),
+                ..Default::default()
+            };
+
+            Ok(iter::once(header)
+                .chain(patterns.iter().map(|pattern| Item {
+                    id: hash(["ignore", pattern]),
+                    depth: 1,
+                    data: ItemData::Raw(pattern.clone()),
+                    ..Default::default()
+                }))
+                .collect())
+        }),
+    )
+}
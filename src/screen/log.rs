@@ -1,8 +1,11 @@
 use super::Screen;
-use crate::{Res, config::Config, items::log};
+use crate::{
+    Res,
+    config::Config,
+    items::{LogFilter, file_log, log, log_range, reflog},
+};
 use git2::{Oid, Repository};
 use ratatui::layout::Size;
-use regex::Regex;
 use std::{rc::Rc, sync::Arc};
 
 pub(crate) fn create(
@@ -11,11 +14,51 @@ pub(crate) fn create(
     size: Size,
     limit: usize,
     rev: Option<Oid>,
-    msg_regex: Option<Regex>,
+    filter: LogFilter,
 ) -> Res<Screen> {
     Screen::new(
         Arc::clone(&config),
         size,
-        Box::new(move || log(&repo, limit, rev, msg_regex.clone())),
+        Box::new(move || log(&repo, limit, rev, &filter)),
+    )
+}
+
+pub(crate) fn create_range(
+    config: Arc<Config>,
+    repo: Rc<Repository>,
+    size: Size,
+    from: Option<Oid>,
+    to: Oid,
+) -> Res<Screen> {
+    Screen::new(
+        Arc::clone(&config),
+        size,
+        Box::new(move || log_range(&repo, from, to)),
+    )
+}
+
+pub(crate) fn create_reflog(
+    config: Arc<Config>,
+    repo: Rc<Repository>,
+    size: Size,
+    refname: String,
+) -> Res<Screen> {
+    Screen::new(
+        Arc::clone(&config),
+        size,
+        Box::new(move || reflog(&repo, &refname)),
+    )
+}
+
+pub(crate) fn create_file_log(
+    config: Arc<Config>,
+    repo: Rc<Repository>,
+    size: Size,
+    path: String,
+) -> Res<Screen> {
+    Screen::new(
+        Arc::clone(&config),
+        size,
+        Box::new(move || file_log(&repo, &path)),
     )
 }
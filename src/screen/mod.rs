@@ -1,4 +1,5 @@
 use crate::config::StyleConfig;
+use crate::menu::Menu;
 use crate::ui::layout::OPTS;
 use crate::ui::{UiTree, layout_span};
 use crate::{item_data::ItemData, ui};
@@ -12,7 +13,12 @@ use std::borrow::Cow;
 use std::collections::HashSet;
 use std::sync::Arc;
 
+pub(crate) mod blame;
+pub(crate) mod clean;
+pub(crate) mod diff;
+pub(crate) mod ignore;
 pub(crate) mod log;
+pub(crate) mod rebase_todo;
 pub(crate) mod show;
 pub(crate) mod show_refs;
 pub(crate) mod show_stash;
@@ -29,6 +35,8 @@ pub(crate) enum NavMode {
 
 pub(crate) struct Screen {
     pub(crate) size: Size,
+    /// A menu kept open while this screen is shown, its bindings take precedence over the root ones
+    pub(crate) menu: Option<Menu>,
     cursor: usize,
     scroll: usize,
     config: Arc<Config>,
@@ -36,6 +44,8 @@ pub(crate) struct Screen {
     items: Vec<Item>,
     line_index: Vec<usize>,
     collapsed: HashSet<u64>,
+    /// Items selected to be acted on together, see `mark_id`
+    marked: HashSet<u64>,
 }
 
 impl Screen {
@@ -56,11 +66,13 @@ impl Screen {
             cursor: 0,
             scroll: 0,
             size,
+            menu: None,
             config,
             refresh_items,
             items: vec![],
             line_index: vec![],
             collapsed,
+            marked: HashSet::new(),
         };
 
         screen.update()?;
@@ -104,6 +116,18 @@ impl Screen {
         self.scroll_fit_start();
     }
 
+    /// Moves the cursor to the first visible item matching `predicate`, if any.
+    pub(crate) fn select_item(&mut self, predicate: impl Fn(&ItemData) -> bool) {
+        if let Some(line_i) = (0..self.line_index.len()).find(|&line_i| {
+            let item = self.at_line(line_i);
+            !item.unselectable && predicate(&item.data)
+        }) {
+            self.cursor = line_i;
+            self.scroll_fit_end();
+            self.scroll_fit_start();
+        }
+    }
+
     fn scroll_fit_start(&mut self) {
         if self.items.is_empty() {
             return;
@@ -222,9 +246,36 @@ impl Screen {
         self.update_line_index();
     }
 
+    pub(crate) fn toggle_mark(&mut self) {
+        let id = mark_id(self.get_selected_item());
+
+        if !self.marked.remove(&id) {
+            self.marked.insert(id);
+        }
+    }
+
+    pub(crate) fn clear_marks(&mut self) {
+        self.marked.clear();
+    }
+
+    pub(crate) fn is_marked(&self, item: &Item) -> bool {
+        self.marked.contains(&mark_id(item))
+    }
+
+    /// The marked items, in the order they're shown
+    pub(crate) fn marked_items(&self) -> Vec<&ItemData> {
+        self.items
+            .iter()
+            .filter(|item| self.is_marked(item))
+            .map(|item| &item.data)
+            .collect()
+    }
+
     pub(crate) fn update(&mut self) -> Res<()> {
         let nav_mode = self.selected_item_nav_mode();
         self.items = (self.refresh_items)()?;
+        let ids = self.items.iter().map(mark_id).collect::<HashSet<_>>();
+        self.marked.retain(|id| ids.contains(id));
         self.update_line_index();
         self.update_cursor(nav_mode);
         Ok(())
@@ -372,6 +423,16 @@ struct LineView<'a> {
     highlighted: bool,
 }
 
+/// Identifies an item to mark. Lines of a hunk or a conflict region share its id,
+/// so they're told apart by where they are in it.
+fn mark_id(item: &Item) -> u64 {
+    match &item.data {
+        ItemData::HunkLine { line_i, .. } => hash((item.id, *line_i)),
+        ItemData::ConflictLine { line_range, .. } => hash((item.id, line_range.start)),
+        _ => item.id,
+    }
+}
+
 const SPACES: &str = "                                                                ";
 
 pub(crate) fn layout_screen<'a>(
@@ -385,14 +446,24 @@ pub(crate) fn layout_screen<'a>(
     layout.vertical(None, OPTS, |layout| {
         for line in screen.line_views(size) {
             layout.horizontal(None, OPTS, |layout| {
+                let item = &screen.items[line.item_index];
                 let is_line_sel = screen.line_index[screen.cursor] == line.item_index;
+                let is_marked = screen.is_marked(item);
                 let area_sel = area_selection_highlight(style, &line);
                 let line_sel = line_selection_highlight(style, &line, is_line_sel);
                 let bg = area_sel.patch(line_sel);
+                let mark_style = if is_marked {
+                    Style::from(&style.marked)
+                } else {
+                    Style::new()
+                };
 
                 let mut line_end = 1;
-                let gutter_char = if !hide_cursor && line.highlighted {
+                let gutter_char = if !hide_cursor && line.highlighted && (is_line_sel || !is_marked)
+                {
                     gutter_char(style, is_line_sel, bg)
+                } else if is_marked {
+                    (style.marked.symbol.to_string().into(), bg.patch(mark_style))
                 } else {
                     (" ".into(), Style::new())
                 };
@@ -400,7 +471,10 @@ pub(crate) fn layout_screen<'a>(
                 layout_span(layout, gutter_char);
 
                 line.display.spans.into_iter().for_each(|span| {
-                    let style = bg.patch(line.display.style).patch(span.style);
+                    let style = bg
+                        .patch(line.display.style)
+                        .patch(span.style)
+                        .patch(mark_style);
 
                     let span_width = span.content.graphemes(true).count();
 
@@ -428,7 +502,6 @@ pub(crate) fn layout_screen<'a>(
                 });
 
                 // Add ellipsis indicator for collapsed sections
-                let item = &screen.items[line.item_index];
                 if screen.is_collapsed(item) {
                     line_end += 1;
                     layout_span(layout, ("…".into(), bg));
@@ -0,0 +1,52 @@
+use super::Screen;
+use crate::{
+    Res,
+    config::Config,
+    git::{self, rebase_todo},
+    item_data::{ItemData, SectionHeader},
+    items::{Item, hash},
+    menu::Menu,
+};
+use git2::Repository;
+use ratatui::layout::Size;
+use std::{iter, rc::Rc, sync::Arc};
+
+/// Lists the remaining lines of `.git/rebase-merge/git-rebase-todo` for editing.
+pub(crate) fn create(config: Arc<Config>, repo: Rc<Repository>, size: Size) -> Res<Screen> {
+    let mut screen =
+        Screen::new(
+            Arc::clone(&config),
+            size,
+            Box::new(move || {
+                let Some(status) = git::rebase_status(&repo)? else {
+                    return Ok(vec![Item {
+                        id: hash("rebase_todo"),
+                        data: ItemData::Raw("No rebase in progress".into()),
+                        unselectable: true,
+                        ..Default::default()
+                    }]);
+                };
+
+                let header = Item {
+                    id: hash("rebase_todo"),
+                    data: ItemData::Header(SectionHeader::Rebase(status.head_name, status.onto)),
+                    unselectable: true,
+                    ..Default::default()
+                };
+
+                Ok(iter::once(header)
+                    .chain(rebase_todo::read(&repo)?.into_iter().enumerate().map(
+                        |(index, line)| Item {
+                            id: hash((index, line.to_string())),
+                            depth: 1,
+                            data: ItemData::RebaseTodoLine { index, line },
+                            ..Default::default()
+                        },
+                    ))
+                    .collect())
+            }),
+        )?;
+
+    screen.menu = Some(Menu::RebaseTodo);
+    Ok(screen)
+}
@@ -3,21 +3,40 @@ use crate::{
     Res,
     config::Config,
     error::Error,
-    git::{self, diff::Diff, status::BranchStatus},
+    git::{
+        self,
+        diff::Diff,
+        rebase_status::RebaseStatus,
+        rebase_todo::TodoLine,
+        status::{BranchStatus, StatusFile},
+    },
+    gitu_diff::Status,
     item_data::{ItemData, SectionHeader},
-    items::{self, Item, hash},
+    items::{self, Item, LogFilter, hash},
 };
 use git2::Repository;
 use ratatui::prelude::Size;
-use std::{hash::Hash, path::PathBuf, rc::Rc, sync::Arc};
+use std::{
+    hash::Hash,
+    iter,
+    path::{Path, PathBuf},
+    rc::Rc,
+    sync::Arc,
+};
 
 enum SectionID {
+    SplitStatus,
     RebaseStatus,
+    AmStatus,
     MergeStatus,
     RevertStatus,
     CherryPickStatus,
+    BisectStatus,
     Untracked,
+    Unmerged,
     Stashes,
+    Submodules,
+    Worktrees,
     RecentCommits,
     BranchStatus,
     UnstagedChanges,
@@ -27,12 +46,18 @@ enum SectionID {
 impl Hash for SectionID {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         let id = match self {
+            SectionID::SplitStatus => "split_status",
             SectionID::RebaseStatus => "rebase_status",
+            SectionID::AmStatus => "am_status",
             SectionID::MergeStatus => "merge_status",
             SectionID::RevertStatus => "revert_status",
             SectionID::CherryPickStatus => "cherry_pick_status",
+            SectionID::BisectStatus => "bisect_status",
             SectionID::Untracked => "untracked",
+            SectionID::Unmerged => "unmerged",
             SectionID::Stashes => "stashes",
+            SectionID::Submodules => "submodules",
+            SectionID::Worktrees => "worktrees",
             SectionID::RecentCommits => "recent_commits",
             SectionID::BranchStatus => "branch_status",
             SectionID::UnstagedChanges => "unstaged_changes",
@@ -48,7 +73,8 @@ pub(crate) fn create(config: Arc<Config>, repo: Rc<Repository>, size: Size) -> R
         Arc::clone(&config),
         size,
         Box::new(move || {
-            let status = git::status(repo.workdir().ok_or(Error::NoRepoWorkdir)?)?;
+            let workdir = repo.workdir().ok_or(Error::NoRepoWorkdir)?;
+            let status = git::status(workdir)?;
             let untracked_files = status
                 .files
                 .iter()
@@ -59,9 +85,15 @@ pub(crate) fn create(config: Arc<Config>, repo: Rc<Repository>, size: Size) -> R
             let untracked = untracked_list(&untracked_files);
 
             let items = if let Some(rebase) = git::rebase_status(&repo)? {
+                create_split_status_items(&repo)?
+                    .into_iter()
+                    .chain(create_rebase_status_items(&repo, rebase))
+                    .collect::<Vec<_>>()
+                    .into_iter()
+            } else if let Some(am) = git::am_status(&repo)? {
                 vec![Item {
-                    id: hash(SectionID::RebaseStatus),
-                    data: ItemData::Header(SectionHeader::Rebase(rebase.head_name, rebase.onto)),
+                    id: hash(SectionID::AmStatus),
+                    data: ItemData::Header(SectionHeader::Am(am.current, am.total)),
                     ..Default::default()
                 }]
                 .into_iter()
@@ -86,6 +118,13 @@ pub(crate) fn create(config: Arc<Config>, repo: Rc<Repository>, size: Size) -> R
                     ..Default::default()
                 }]
                 .into_iter()
+            } else if let Some(bisect) = git::bisect_status(&repo)? {
+                vec![Item {
+                    id: hash(SectionID::BisectStatus),
+                    data: ItemData::Header(SectionHeader::Bisect(bisect)),
+                    ..Default::default()
+                }]
+                .into_iter()
             } else {
                 branch_status_items(&status.branch_status)?.into_iter()
             }
@@ -105,18 +144,21 @@ pub(crate) fn create(config: Arc<Config>, repo: Rc<Repository>, size: Size) -> R
                 ]
             })
             .chain(untracked)
+            .chain(create_unmerged_section_items(workdir, &status.files))
             .chain(create_status_section_items(
                 SectionID::UnstagedChanges,
-                &Rc::new(git::diff_unstaged(repo.as_ref())?),
+                &Rc::new(without_unmerged(git::diff_unstaged(repo.as_ref())?)),
             ))
             .chain(create_status_section_items(
                 SectionID::StagedChanges,
-                &Rc::new(git::diff_staged(repo.as_ref())?),
+                &Rc::new(without_unmerged(git::diff_staged(repo.as_ref())?)),
             ))
             .chain(create_stash_list_section_items(
                 repo.as_ref(),
                 config.general.stash_list_limit,
             ))
+            .chain(create_submodule_section_items(repo.as_ref())?)
+            .chain(create_worktree_section_items(repo.as_ref())?)
             .chain(create_log_section_items(
                 repo.as_ref(),
                 config.general.recent_commits_limit,
@@ -140,6 +182,59 @@ fn untracked_list(files: &[&String]) -> Vec<Item> {
         .collect::<Vec<_>>()
 }
 
+fn create_unmerged_section_items(workdir: &Path, files: &[StatusFile]) -> Vec<Item> {
+    let unmerged = files
+        .iter()
+        .filter_map(|file| file.conflict().map(|conflict| (&file.path, conflict)))
+        .collect::<Vec<_>>();
+
+    if unmerged.is_empty() {
+        return vec![];
+    }
+
+    let mut items = vec![
+        items::blank_line(),
+        Item {
+            id: hash(SectionID::Unmerged),
+            depth: 0,
+            data: ItemData::AllUnmerged(unmerged.len()),
+            ..Default::default()
+        },
+    ];
+
+    for (path, conflict) in unmerged {
+        // Files deleted on either side have no conflict markers to read
+        let file = git::conflict::read_conflicts(workdir, path)
+            .ok()
+            .filter(|file| !file.regions.is_empty())
+            .map(Rc::new);
+
+        items.push(Item {
+            id: hash(["unmerged", path]),
+            default_collapsed: file.is_some(),
+            depth: 1,
+            data: ItemData::Unmerged {
+                path: PathBuf::from(path),
+                conflict,
+            },
+            ..Default::default()
+        });
+
+        if let Some(file) = file {
+            items.extend(items::create_conflict_items(&file, 2));
+        }
+    }
+
+    items
+}
+
+/// Unmerged paths get a section of their own, rather than a bodiless entry among the changes.
+fn without_unmerged(mut diff: Diff) -> Diff {
+    diff.file_diffs
+        .retain(|file_diff| file_diff.header.status != Status::Unmerged);
+    diff
+}
+
 fn branch_status_items(status: &BranchStatus) -> Res<Vec<Item>> {
     let Some(ref head) = status.local else {
         return Ok(vec![Item {
@@ -222,6 +317,167 @@ fn create_stash_list_section_items<'a>(
     .chain(stashes)
 }
 
+/// A banner on top of the rebase while a commit is being split.
+fn create_split_status_items(repo: &Repository) -> Res<Vec<Item>> {
+    Ok(match git::split_status(repo)? {
+        Some(split) => vec![
+            Item {
+                id: hash(SectionID::SplitStatus),
+                data: ItemData::Header(SectionHeader::Split(split.commit, split.created)),
+                ..Default::default()
+            },
+            items::blank_line(),
+        ],
+        None => vec![],
+    })
+}
+
+/// Lists the rebase like Magit: remaining todo lines (last one on top),
+/// the commit git stopped at, the applied ones and finally the base.
+fn create_rebase_status_items(repo: &Repository, rebase: RebaseStatus) -> Vec<Item> {
+    let header = Item {
+        id: hash(SectionID::RebaseStatus),
+        data: ItemData::Header(SectionHeader::Rebase(rebase.head_name, rebase.onto)),
+        ..Default::default()
+    };
+
+    let stopped_i = rebase
+        .stopped
+        .then(|| rebase.done.len().checked_sub(1))
+        .flatten();
+
+    let todo = rebase.todo.iter().rev().filter_map(|line| match line {
+        TodoLine::Commit {
+            action,
+            oid,
+            summary,
+        } => Some((action.as_str(), oid, summary.as_str())),
+        _ => None,
+    });
+
+    let done = rebase
+        .done
+        .iter()
+        .enumerate()
+        .rev()
+        .filter_map(|(i, line)| match line {
+            TodoLine::Commit { oid, summary, .. } => {
+                let prefix = if Some(i) == stopped_i { "stop" } else { "done" };
+                Some((prefix, oid, summary.as_str()))
+            }
+            _ => None,
+        });
+
+    iter::once(header)
+        .chain(
+            todo.chain(done)
+                .chain([("onto", &rebase.onto_oid, "")])
+                .map(|(prefix, oid, summary)| rebase_commit_item(repo, prefix, oid, summary)),
+        )
+        .collect()
+}
+
+fn rebase_commit_item(repo: &Repository, prefix: &'static str, rev: &str, summary: &str) -> Item {
+    let commit = repo
+        .revparse_single(rev)
+        .and_then(|object| object.peel_to_commit())
+        .ok();
+
+    let (oid, short_id, summary) = match commit {
+        Some(commit) => (
+            commit.id().to_string(),
+            commit
+                .as_object()
+                .short_id()
+                .ok()
+                .and_then(|id| id.as_str().map(String::from))
+                .unwrap_or_else(|| rev.chars().take(7).collect()),
+            commit.summary().unwrap_or(summary).to_string(),
+        ),
+        None => (
+            rev.to_string(),
+            rev.chars().take(7).collect(),
+            summary.to_string(),
+        ),
+    };
+
+    Item {
+        id: hash((prefix, &oid)),
+        depth: 1,
+        data: ItemData::Commit {
+            prefix,
+            graph: vec![],
+            oid,
+            short_id,
+            associated_references: vec![],
+            summary,
+            collapsible: false,
+        },
+        ..Default::default()
+    }
+}
+
+fn create_submodule_section_items(repo: &Repository) -> Res<Vec<Item>> {
+    let submodules = git::submodule::list(repo)?;
+    if submodules.is_empty() {
+        return Ok(vec![]);
+    }
+
+    Ok([
+        items::blank_line(),
+        Item {
+            id: hash(SectionID::Submodules),
+            depth: 0,
+            data: ItemData::Header(SectionHeader::Submodules),
+            ..Default::default()
+        },
+    ]
+    .into_iter()
+    .chain(submodules.into_iter().map(|submodule| Item {
+        id: hash(&submodule.path),
+        depth: 1,
+        data: ItemData::Submodule {
+            path: submodule.path,
+            recorded: submodule.recorded.map(|oid| oid.to_string()),
+            checked_out: submodule.checked_out.map(|oid| oid.to_string()),
+            dirty: submodule.dirty,
+        },
+        ..Default::default()
+    }))
+    .collect())
+}
+
+fn create_worktree_section_items(repo: &Repository) -> Res<Vec<Item>> {
+    let workdir = repo.workdir().ok_or(Error::NoRepoWorkdir)?;
+    let worktrees = git::worktree::list_others(repo)?;
+    if worktrees.is_empty() {
+        return Ok(vec![]);
+    }
+
+    Ok([
+        items::blank_line(),
+        Item {
+            id: hash(SectionID::Worktrees),
+            depth: 0,
+            data: ItemData::Header(SectionHeader::Worktrees),
+            ..Default::default()
+        },
+    ]
+    .into_iter()
+    .chain(worktrees.into_iter().map(|worktree| Item {
+        id: hash(&worktree.path),
+        depth: 1,
+        data: ItemData::Worktree {
+            display_path: git::worktree::display_path(&worktree.path, workdir),
+            path: worktree.path,
+            branch: worktree.branch,
+            head: worktree.head,
+        },
+        ..Default::default()
+    }))
+    .collect())
+}
+
 fn create_log_section_items<'a>(
     repo: &Repository,
     limit: usize,
@@ -240,5 +496,5 @@ fn create_log_section_items<'a>(
         },
     ]
     .into_iter()
-    .chain(items::log(repo, limit, None, None).unwrap())
+    .chain(items::log(repo, limit, None, &LogFilter::default()).unwrap())
 }
@@ -0,0 +1,95 @@
+use super::*;
+
+fn setup(ctx: &TestContext) {
+    commit(&ctx.dir, "file-1", "");
+    run(&ctx.dir, &["git", "tag", "good"]);
+    commit(&ctx.dir, "file-2", "");
+    commit(&ctx.dir, "file-3", "");
+    commit(&ctx.dir, "file-4", "");
+    commit(&ctx.dir, "file-5", "");
+}
+
+fn setup_bisecting(ctx: &TestContext) {
+    setup(ctx);
+    run(&ctx.dir, &["git", "bisect", "start", "HEAD", "good"]);
+}
+
+#[test]
+fn bisect_menu() {
+    let ctx = setup_clone!();
+    setup(&ctx);
+    snapshot!(ctx, "B");
+}
+
+#[test]
+fn bisect_start() {
+    let ctx = setup_clone!();
+    setup(&ctx);
+    snapshot!(ctx, "BB<enter>good<enter>");
+}
+
+#[test]
+fn bisect_started_without_revisions() {
+    let ctx = setup_clone!();
+    setup(&ctx);
+    run(&ctx.dir, &["git", "bisect", "start"]);
+    snapshot!(ctx, "");
+}
+
+#[test]
+fn bisect_status() {
+    let ctx = setup_clone!();
+    setup_bisecting(&ctx);
+    snapshot!(ctx, "");
+}
+
+#[test]
+fn bisect_mark_good() {
+    let ctx = setup_clone!();
+    setup_bisecting(&ctx);
+    snapshot!(ctx, "Bg");
+}
+
+#[test]
+fn bisect_mark_bad_from_log() {
+    let ctx = setup_clone!();
+    setup_bisecting(&ctx);
+    snapshot!(ctx, "lljBbq");
+}
+
+#[test]
+fn bisect_skip() {
+    let ctx = setup_clone!();
+    setup_bisecting(&ctx);
+    snapshot!(ctx, "Bk");
+}
+
+#[test]
+fn bisect_found_first_bad() {
+    let ctx = setup_clone!();
+    setup_bisecting(&ctx);
+    run(&ctx.dir, &["git", "bisect", "good"]);
+    run(&ctx.dir, &["git", "bisect", "bad"]);
+    snapshot!(ctx, "");
+}
+
+#[test]
+fn bisect_reset() {
+    let ctx = setup_clone!();
+    setup_bisecting(&ctx);
+    snapshot!(ctx, "Br");
+}
+
+#[test]
+fn bisect_run() {
+    let ctx = setup_clone!();
+    setup_bisecting(&ctx);
+    snapshot!(ctx, "Bs! test -e file-4<enter>");
+}
+
+#[test]
+fn bisect_run_empty_script() {
+    let ctx = setup_clone!();
+    setup_bisecting(&ctx);
+    snapshot!(ctx, "Bs<enter>");
+}
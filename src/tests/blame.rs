@@ -0,0 +1,86 @@
+use super::*;
+use crate::cli::{Args, Commands};
+
+fn setup(ctx: &TestContext) {
+    commit(
+        &ctx.dir,
+        "file.rs",
+        "fn main() {\n    let a = 1;\n    let b = 2;\n}\n",
+    );
+    commit(
+        &ctx.dir,
+        "file.rs",
+        "fn main() {\n    let a = 10;\n    let b = 2;\n}\n",
+    );
+    fs::write(
+        ctx.dir.join("file.rs"),
+        "fn main() {\n    let a = 10;\n    let b = 20;\n}\n",
+    )
+    .unwrap();
+}
+
+#[test]
+fn blame_hunk() {
+    let ctx = setup_clone!();
+    setup(&ctx);
+    snapshot!(ctx, "jj<tab>je");
+}
+
+#[test]
+fn blame_delta() {
+    let ctx = setup_clone!();
+    setup(&ctx);
+    snapshot!(ctx, "jje");
+}
+
+#[test]
+fn blame_from_show() {
+    let ctx = setup_clone!();
+    setup(&ctx);
+    snapshot!(ctx, "ll<enter>jje");
+}
+
+#[test]
+fn blame_show_chunk() {
+    let ctx = setup_clone!();
+    setup(&ctx);
+    snapshot!(ctx, "jj<tab>jekkk<enter>");
+}
+
+#[test]
+fn blame_parent() {
+    let ctx = setup_clone!();
+    setup(&ctx);
+    snapshot!(ctx, "jj<tab>jekp");
+}
+
+#[test]
+fn blame_parent_of_first_commit() {
+    let ctx = setup_clone!();
+    setup(&ctx);
+    snapshot!(ctx, "jj<tab>jekkkpp");
+}
+
+#[test]
+fn blame_command() {
+    let mut ctx = setup_clone!();
+    setup(&ctx);
+
+    let args = Args {
+        command: Some(Commands::Blame {
+            path: ctx.dir.join("file.rs"),
+            reference: Some("HEAD~".into()),
+        }),
+        ..Default::default()
+    };
+
+    ctx.init_app_with_args(ctx.dir.clone(), &args);
+    insta::assert_snapshot!(ctx.redact_buffer());
+}
+
+#[test]
+fn blame_hunk_line() {
+    let ctx = setup_clone!();
+    setup(&ctx);
+    snapshot!(ctx, "jj<tab>j<ctrl+j><ctrl+j>e");
+}
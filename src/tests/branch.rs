@@ -48,6 +48,50 @@ fn checkout_use_custom_input() {
     snapshot!(ctx, &format!("bb{}<enter>", commit_hash));
 }
 
+fn setup_remote_branch(ctx: TestContext) -> TestContext {
+    run(&ctx.dir, &["git", "push", "origin", "main:feature"]);
+    ctx
+}
+
+#[test]
+fn checkout_remote_branch_prompt() {
+    snapshot!(
+        setup_remote_branch(setup_clone!()),
+        "bborigin/feature<enter>"
+    );
+}
+
+#[test]
+fn checkout_remote_branch() {
+    snapshot!(
+        setup_remote_branch(setup_clone!()),
+        "bborigin/feature<enter><enter>"
+    );
+}
+
+#[test]
+fn checkout_remote_branch_reuses_tracking_branch() {
+    let ctx = setup_remote_branch(setup_clone!());
+    run(
+        &ctx.dir,
+        &["git", "branch", "--track", "feature", "origin/feature"],
+    );
+    snapshot!(ctx, "bborigin/feature<enter>");
+}
+
+#[test]
+fn checkout_local_branch_picker() {
+    snapshot!(setup_remote_branch(setup_picker(setup_clone!())), "bl");
+}
+
+#[test]
+fn checkout_local_branch_from_remote() {
+    snapshot!(
+        setup_remote_branch(setup_picker(setup_clone!())),
+        "blorigin/feature<enter><enter>"
+    );
+}
+
 // ==================== Delete Tests ====================
 
 #[test]
@@ -76,6 +120,131 @@ fn delete_unmerged_branch() {
     snapshot!(ctx, "bKbugfix-123<enter>nbKbugfix-123<enter>y");
 }
 
+#[test]
+fn delete_remote_branch() {
+    let ctx = setup_clone!();
+    let dir = ctx.dir.clone();
+    run(&dir, &["git", "push", "origin", "main:feature"]);
+    snapshot!(ctx, "YjjjjbK<enter>y");
+    assert_eq!(
+        run(&dir, &["git", "ls-remote", "--heads", "origin", "feature"]),
+        ""
+    );
+}
+
+#[test]
+fn delete_remote_branch_abort() {
+    let ctx = setup_clone!();
+    let dir = ctx.dir.clone();
+    run(&dir, &["git", "push", "origin", "main:feature"]);
+    snapshot!(ctx, "YjjjjbK<enter>n");
+    assert_ne!(
+        run(&dir, &["git", "ls-remote", "--heads", "origin", "feature"]),
+        ""
+    );
+}
+
+fn setup_merged(ctx: TestContext) -> TestContext {
+    run(&ctx.dir, &["git", "branch", "merged-a"]);
+    run(&ctx.dir, &["git", "branch", "merged-b"]);
+    run(&ctx.dir, &["git", "checkout", "-b", "unmerged"]);
+    commit(&ctx.dir, "unmerged commit", "");
+    run(&ctx.dir, &["git", "checkout", "main"]);
+    ctx
+}
+
+#[test]
+fn delete_marked_merged_branches() {
+    snapshot!(setup_merged(setup_clone!()), "Yjj<space>j<space>bK");
+}
+
+#[test]
+fn delete_marked_keeps_unmerged_branches() {
+    snapshot!(
+        setup_merged(setup_clone!()),
+        "Yjj<space>j<space>j<space>bKn"
+    );
+}
+
+#[test]
+fn delete_marked_unmerged_branches() {
+    snapshot!(
+        setup_merged(setup_clone!()),
+        "Yjj<space>j<space>j<space>bKy"
+    );
+}
+
+#[test]
+fn delete_marked_current_branch() {
+    snapshot!(setup_merged(setup_clone!()), "Yj<space>j<space>bK");
+}
+
+// ==================== Create Tests ====================
+
+#[test]
+fn create_branch_picker() {
+    snapshot!(setup_picker(setup_clone!()), "bn");
+}
+
+#[test]
+fn create_branch() {
+    snapshot!(setup_picker(setup_clone!()), "bn<enter>new-branch<enter>");
+}
+
+#[test]
+fn create_branch_at_commit() {
+    let ctx = setup_picker(setup_clone!());
+    let dir = ctx.dir.clone();
+    commit(&dir, "second-file", "");
+    snapshot!(ctx, "lljbn<enter>at-commit<enter>");
+    assert_eq!(
+        run(&dir, &["git", "rev-parse", "at-commit"]),
+        run(&dir, &["git", "rev-parse", "main~"])
+    );
+}
+
+#[test]
+fn create_branch_empty_name() {
+    snapshot!(setup_picker(setup_clone!()), "bn<enter><enter>");
+}
+
+// ==================== Reset Tests ====================
+
+#[test]
+fn reset_branch_picker() {
+    snapshot!(setup_picker(setup_clone!()), "bx");
+}
+
+#[test]
+fn reset_branch() {
+    let ctx = setup_picker(setup_clone!());
+    let dir = ctx.dir.clone();
+    snapshot!(ctx, "bxfeature-a<enter><enter>");
+    assert_eq!(
+        run(&dir, &["git", "rev-parse", "feature-a"]),
+        run(&dir, &["git", "rev-parse", "main"])
+    );
+}
+
+#[test]
+fn reset_current_branch() {
+    let ctx = setup_picker(setup_clone!());
+    let dir = ctx.dir.clone();
+    commit(&dir, "second-file", "");
+    snapshot!(ctx, "bx<enter>HEAD~<enter>y");
+    assert_eq!(
+        run(&dir, &["git", "rev-parse", "main"]),
+        run(&dir, &["git", "rev-parse", "origin/main"])
+    );
+}
+
+#[test]
+fn reset_current_branch_abort() {
+    let ctx = setup_picker(setup_clone!());
+    commit(&ctx.dir, "second-file", "");
+    snapshot!(ctx, "bx<enter>HEAD~<enter>n");
+}
+
 // ==================== Rename Tests ====================
 
 #[test]
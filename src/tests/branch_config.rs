@@ -0,0 +1,72 @@
+use super::*;
+use temp_env::with_var;
+
+#[test]
+fn branch_config_menu() {
+    snapshot!(setup_clone!(), "bC");
+}
+
+#[test]
+fn branch_config_menu_detached() {
+    let ctx = setup_clone!();
+    run(&ctx.dir, &["git", "checkout", "--detach"]);
+    snapshot!(ctx, "bC");
+}
+
+#[test]
+fn set_upstream_detached() {
+    let ctx = setup_clone!();
+    run(&ctx.dir, &["git", "checkout", "--detach"]);
+    snapshot!(ctx, "bCu");
+}
+
+#[test]
+fn set_description() {
+    with_var("GIT_EDITOR", Some("echo 'Fix all the things' >"), || {
+        snapshot!(setup_clone!(), "bCd");
+    });
+}
+
+#[test]
+fn set_upstream_picker() {
+    let ctx = setup_clone!();
+    run(&ctx.dir, &["git", "checkout", "-b", "feature"]);
+    snapshot!(ctx, "bCu");
+}
+
+#[test]
+fn set_upstream() {
+    let ctx = setup_clone!();
+    run(&ctx.dir, &["git", "checkout", "-b", "feature"]);
+    snapshot!(ctx, "bCuorigin/main<enter>");
+}
+
+#[test]
+fn unset_upstream() {
+    snapshot!(setup_clone!(), "bCU");
+}
+
+#[test]
+fn cycle_rebase_true() {
+    snapshot!(setup_clone!(), "bCr");
+}
+
+#[test]
+fn cycle_rebase_false() {
+    snapshot!(setup_clone!(), "bCrr");
+}
+
+#[test]
+fn cycle_rebase_unset() {
+    snapshot!(setup_clone!(), "bCrrr");
+}
+
+#[test]
+fn cycle_push_remote() {
+    snapshot!(setup_clone!(), "bCp");
+}
+
+#[test]
+fn cycle_push_remote_unset() {
+    snapshot!(setup_clone!(), "bCpp");
+}
@@ -0,0 +1,82 @@
+use super::*;
+
+fn setup(ctx: TestContext) -> TestContext {
+    commit(&ctx.dir, ".gitignore", "*.log\n");
+    run(&ctx.dir, &["touch", "file-a", "file-b", "debug.log"]);
+    fs::create_dir(ctx.dir.join("dir")).unwrap();
+    run(&ctx.dir, &["touch", "dir/nested"]);
+    ctx
+}
+
+#[test]
+fn clean_menu() {
+    snapshot!(setup(setup_clone!()), "C");
+}
+
+#[test]
+fn clean_preview() {
+    snapshot!(setup(setup_clone!()), "CC");
+}
+
+#[test]
+fn clean() {
+    let ctx = setup(setup_clone!());
+    let dir = ctx.dir.clone();
+    snapshot!(ctx, "CCy");
+    assert!(!dir.join("file-a").exists());
+    assert!(!dir.join("file-b").exists());
+    assert!(dir.join("dir/nested").exists());
+    assert!(dir.join("debug.log").exists());
+}
+
+#[test]
+fn clean_abort() {
+    let ctx = setup(setup_clone!());
+    let dir = ctx.dir.clone();
+    snapshot!(ctx, "CCn");
+    assert!(dir.join("file-a").exists());
+}
+
+#[test]
+fn clean_selected_file() {
+    let ctx = setup(setup_clone!());
+    let dir = ctx.dir.clone();
+    snapshot!(ctx, "jjjCCy");
+    assert!(!dir.join("file-a").exists());
+    assert!(dir.join("file-b").exists());
+}
+
+#[test]
+fn clean_directories() {
+    snapshot!(setup(setup_clone!()), "C-dC");
+}
+
+#[test]
+fn clean_ignored() {
+    snapshot!(setup(setup_clone!()), "C-xC");
+}
+
+#[test]
+fn clean_only_ignored() {
+    snapshot!(setup(setup_clone!()), "C-XC");
+}
+
+#[test]
+fn clean_path() {
+    snapshot!(setup(setup_clone!()), "C-d--dir<enter>C");
+}
+
+#[test]
+fn clean_path_with_space() {
+    let ctx = setup(setup_clone!());
+    run(&ctx.dir, &["touch", "my file"]);
+    let dir = ctx.dir.clone();
+    snapshot!(ctx, "C--\"my file\"<enter>Cy");
+    assert!(!dir.join("my file").exists());
+    assert!(dir.join("file-a").exists());
+}
+
+#[test]
+fn nothing_to_clean() {
+    snapshot!(setup_clone!(), "CC");
+}
@@ -0,0 +1,78 @@
+use super::*;
+
+fn setup(ctx: &TestContext) {
+    commit(&ctx.dir, "file-a", "a\n");
+    commit(&ctx.dir, "file-b", "b\n");
+    commit(&ctx.dir, "file-c", "c\n");
+}
+
+#[test]
+fn diff_menu() {
+    let ctx = setup_clone!();
+    setup(&ctx);
+    snapshot!(ctx, "d");
+}
+
+#[test]
+fn diff_range() {
+    let ctx = setup_clone!();
+    setup(&ctx);
+    snapshot!(ctx, "drHEAD~2<enter><enter>");
+}
+
+#[test]
+fn diff_range_no_changes() {
+    let ctx = setup_clone!();
+    setup(&ctx);
+    snapshot!(ctx, "dr<enter><enter>");
+}
+
+#[test]
+fn diff_merge_base() {
+    let ctx = setup_clone!();
+    setup(&ctx);
+    run(&ctx.dir, &["git", "checkout", "-b", "feature", "HEAD~1"]);
+    commit(&ctx.dir, "file-d", "d\n");
+    snapshot!(ctx, "dmmain<enter><enter>");
+}
+
+#[test]
+fn diff_worktree() {
+    let ctx = setup_clone!();
+    setup(&ctx);
+    fs::write(ctx.dir.join("file-a"), "unstaged\n").unwrap();
+    snapshot!(ctx, "dwHEAD~1<enter>");
+}
+
+#[test]
+fn diff_index() {
+    let ctx = setup_clone!();
+    setup(&ctx);
+    fs::write(ctx.dir.join("file-a"), "staged\n").unwrap();
+    run(&ctx.dir, &["git", "add", "file-a"]);
+    fs::write(ctx.dir.join("file-b"), "unstaged\n").unwrap();
+    snapshot!(ctx, "diHEAD~1<enter>");
+}
+
+#[test]
+fn diff_path() {
+    let ctx = setup_clone!();
+    setup(&ctx);
+    snapshot!(ctx, "dpfile-b<enter>HEAD~3<enter><enter>");
+}
+
+#[test]
+fn diff_path_required() {
+    let ctx = setup_clone!();
+    setup(&ctx);
+    snapshot!(ctx, "dp<enter>");
+}
+
+#[test]
+fn diff_range_discard_does_nothing() {
+    let ctx = setup_clone!();
+    setup(&ctx);
+    let dir = ctx.dir.clone();
+    snapshot!(ctx, "drHEAD~2<enter><enter>Ky");
+    assert_eq!(run(&dir, &["git", "status", "--porcelain"]), "");
+}
@@ -138,3 +138,15 @@ fn unmerged_branch_selected_unmerged_confirm() {
 fn unmerged_branch_selected() {
     snapshot!(setup(setup_clone!()), "YjjjKyy");
 }
+
+#[test]
+fn discard_hunk_in_show() {
+    let ctx = setup_clone!();
+    commit(&ctx.dir, "new-file", "hello\n");
+    let dir = ctx.dir.clone();
+    snapshot!(ctx, "ll<enter>jKy");
+    assert_eq!(
+        run(&dir, &["git", "status", "--porcelain"]),
+        "D  new-file\n"
+    );
+}
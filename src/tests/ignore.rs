@@ -0,0 +1,155 @@
+use super::*;
+
+fn setup(ctx: TestContext) -> TestContext {
+    fs::create_dir(ctx.dir.join("sub")).unwrap();
+    commit(&ctx.dir, "sub/tracked", "");
+    run(&ctx.dir, &["touch", "build.log", "sub/notes.txt"]);
+    ctx
+}
+
+#[test]
+fn ignore_menu() {
+    snapshot!(setup(setup_clone!()), "jji");
+}
+
+#[test]
+fn ignore_menu_all_untracked() {
+    snapshot!(setup(setup_clone!()), "ji");
+}
+
+#[test]
+fn ignore_picker() {
+    snapshot!(setup(setup_clone!()), "jjit");
+}
+
+#[test]
+fn ignore_preview() {
+    snapshot!(setup(setup_clone!()), "jjit<enter>");
+}
+
+#[test]
+fn ignore_toplevel() {
+    let ctx = setup(setup_clone!());
+    let dir = ctx.dir.clone();
+    snapshot!(ctx, "jjit<enter>y");
+    assert_eq!(
+        fs::read_to_string(dir.join(".gitignore")).unwrap(),
+        "/build.log\n"
+    );
+}
+
+#[test]
+fn ignore_glob() {
+    let ctx = setup(setup_clone!());
+    let dir = ctx.dir.clone();
+    snapshot!(ctx, "jjit<down><enter>y");
+    assert_eq!(
+        fs::read_to_string(dir.join(".gitignore")).unwrap(),
+        "*.log\n"
+    );
+}
+
+#[test]
+fn ignore_directory() {
+    let ctx = setup(setup_clone!());
+    let dir = ctx.dir.clone();
+    snapshot!(ctx, "jjjit<down><enter>y");
+    assert_eq!(
+        fs::read_to_string(dir.join(".gitignore")).unwrap(),
+        "/sub/\n"
+    );
+}
+
+#[test]
+fn ignore_subdirectory() {
+    let ctx = setup(setup_clone!());
+    let dir = ctx.dir.clone();
+    snapshot!(ctx, "jjjis<enter>y");
+    assert_eq!(
+        fs::read_to_string(dir.join("sub/.gitignore")).unwrap(),
+        "/notes.txt\n"
+    );
+}
+
+#[test]
+fn ignore_private() {
+    let ctx = setup(setup_clone!());
+    let dir = ctx.dir.clone();
+    snapshot!(ctx, "jjip<enter>y");
+    assert!(
+        fs::read_to_string(dir.join(".git/info/exclude"))
+            .unwrap()
+            .ends_with("\n/build.log\n")
+    );
+}
+
+#[test]
+fn ignore_private_in_worktree() {
+    let mut ctx = setup_clone!();
+    run(
+        &ctx.dir,
+        &["git", "worktree", "add", "-b", "other-branch", "../other"],
+    );
+    let other = ctx.dir.join("../other");
+    run(&other, &["touch", "build.log"]);
+
+    let mut app = ctx.init_app_at_path(other.clone());
+    ctx.update(&mut app, keys("jip<enter>y"));
+
+    insta::assert_snapshot!(ctx.redact_buffer());
+    assert!(
+        fs::read_to_string(ctx.dir.join(".git/info/exclude"))
+            .unwrap()
+            .ends_with("\n/build.log\n")
+    );
+    assert_eq!(run(&other, &["git", "status", "--porcelain"]), "");
+}
+
+#[test]
+fn ignore_global() {
+    let ctx = setup(setup_clone!());
+    let dir = ctx.dir.clone();
+    let excludes_file = dir.join(".git/global-ignore");
+    run(
+        &dir,
+        &[
+            "git",
+            "config",
+            "core.excludesFile",
+            excludes_file.to_str().unwrap(),
+        ],
+    );
+    snapshot!(ctx, "jjig<enter>y");
+    assert_eq!(fs::read_to_string(excludes_file).unwrap(), "/build.log\n");
+}
+
+#[test]
+fn ignore_all_untracked() {
+    let ctx = setup(setup_clone!());
+    let dir = ctx.dir.clone();
+    snapshot!(ctx, "jit<enter>y");
+    assert_eq!(
+        fs::read_to_string(dir.join(".gitignore")).unwrap(),
+        "/build.log\n/sub/notes.txt\n"
+    );
+}
+
+#[test]
+fn ignore_appends_to_existing_file() {
+    let ctx = setup(setup_clone!());
+    let dir = ctx.dir.clone();
+    commit(&dir, ".gitignore", "*.tmp");
+    snapshot!(ctx, "jjit<enter>y");
+    assert_eq!(
+        fs::read_to_string(dir.join(".gitignore")).unwrap(),
+        "*.tmp\n/build.log\n"
+    );
+}
+
+#[test]
+fn ignore_abort() {
+    let ctx = setup(setup_clone!());
+    let dir = ctx.dir.clone();
+    snapshot!(ctx, "jjit<enter>n");
+    assert!(!dir.join(".gitignore").exists());
+}
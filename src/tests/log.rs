@@ -76,3 +76,172 @@ fn log_other_input() {
 fn log_other_invalid() {
     snapshot!(setup(setup_clone!()), "lo <enter>");
 }
+
+#[test]
+fn graph_merge() {
+    snapshot!(setup_merge(setup_clone!()), "ll");
+}
+
+#[test]
+fn graph_ascii() {
+    let mut ctx = setup_merge(setup_clone!());
+    ctx.config().style.graph.ascii = true;
+    snapshot!(ctx, "ll");
+}
+
+fn setup_filters(ctx: TestContext) -> TestContext {
+    commit(&ctx.dir, "lib.rs", "fn foo() {}\n");
+    commit(&ctx.dir, "main.rs", "fn main() {}\n");
+    commit(&ctx.dir, "lib.rs", "fn foo() {}\nfn bar() {}\n");
+    run(
+        &ctx.dir,
+        &[
+            "git",
+            "commit",
+            "--allow-empty",
+            "--author",
+            "Someone Else <someone@example.com>",
+            "-m",
+            "empty commit",
+        ],
+    );
+    ctx
+}
+
+#[test]
+fn filter_menu() {
+    snapshot!(
+        setup_filters(setup_clone!()),
+        "l-AElse<enter>-m--lib.rs<enter>"
+    );
+}
+
+#[test]
+fn filter_author() {
+    snapshot!(setup_filters(setup_clone!()), "l-AElse<enter>l");
+}
+
+#[test]
+fn filter_search_occurrences() {
+    snapshot!(setup_filters(setup_clone!()), "l-Sbar<enter>l");
+}
+
+#[test]
+fn filter_search_changes() {
+    snapshot!(setup_filters(setup_clone!()), "l-Gfn<enter>l");
+}
+
+#[test]
+fn filter_paths() {
+    snapshot!(setup_filters(setup_clone!()), "l--lib.rs main.rs<enter>l");
+}
+
+#[test]
+fn filter_until() {
+    snapshot!(setup_filters(setup_clone!()), "l-u2000-01-01<enter>l");
+}
+
+#[test]
+fn filter_paths_with_space() {
+    let ctx = setup_filters(setup_clone!());
+    commit(&ctx.dir, "my file.rs", "");
+    commit(&ctx.dir, "file.rs", "");
+    snapshot!(ctx, "l--\"my file.rs\"<enter>l");
+}
+
+fn setup_dates(ctx: TestContext) -> TestContext {
+    for (date, message) in [
+        ("2023-06-01T12:00:00", "commit from 2023"),
+        ("2025-06-01T12:00:00", "commit from 2025"),
+    ] {
+        run(
+            &ctx.dir,
+            &[
+                "env",
+                &format!("GIT_COMMITTER_DATE={date}"),
+                "git",
+                "commit",
+                "--allow-empty",
+                "-m",
+                message,
+            ],
+        );
+    }
+    ctx
+}
+
+#[test]
+fn filter_since() {
+    snapshot!(setup_dates(setup_clone!()), "l-s2025-01-01<enter>l");
+}
+
+#[test]
+fn filter_since_until() {
+    snapshot!(
+        setup_dates(setup_clone!()),
+        "l-s2023-01-01<enter>-u2024-01-01<enter>l"
+    );
+}
+
+fn setup_merge(ctx: TestContext) -> TestContext {
+    run(&ctx.dir, &["git", "checkout", "-b", "other-branch"]);
+    commit(&ctx.dir, "other-file", "");
+    run(&ctx.dir, &["git", "checkout", "main"]);
+    commit(&ctx.dir, "main-file", "");
+    run(
+        &ctx.dir,
+        &["git", "merge", "--no-ff", "--no-edit", "other-branch"],
+    );
+    ctx
+}
+
+#[test]
+fn filter_no_merges() {
+    snapshot!(setup_merge(setup_clone!()), "l-ml");
+}
+
+#[test]
+fn filter_first_parent() {
+    snapshot!(setup_merge(setup_clone!()), "l-pl");
+}
+
+fn setup_file_history(ctx: TestContext) -> TestContext {
+    commit(&ctx.dir, "old-name", "first\n");
+    commit(&ctx.dir, "unrelated-file", "");
+    commit(&ctx.dir, "old-name", "first\nsecond\n");
+    run(&ctx.dir, &["git", "mv", "old-name", "new-name"]);
+    run(&ctx.dir, &["git", "commit", "-m", "rename old-name"]);
+    fs::write(ctx.dir.join("new-name"), "first\nsecond\nthird\n").unwrap();
+    ctx
+}
+
+#[test]
+fn file_history_menu() {
+    snapshot!(setup_file_history(setup_clone!()), "jjl");
+}
+
+#[test]
+fn file_history() {
+    snapshot!(setup_file_history(setup_clone!()), "jjlf");
+}
+
+#[test]
+fn file_history_expand_commit() {
+    snapshot!(setup_file_history(setup_clone!()), "jjlfj<tab>");
+}
+
+#[test]
+fn file_history_from_hunk() {
+    snapshot!(setup_file_history(setup_clone!()), "jj<tab>jlfjj<tab>");
+}
+
+#[test]
+fn log_commit_not_foldable() {
+    let mut ctx = setup_clone!();
+    let mut app = ctx.init_app();
+    ctx.update(&mut app, keys("llh"));
+
+    let buffer = ctx.redact_buffer();
+    assert!(!buffer.contains("Fold"));
+    insta::assert_snapshot!(buffer);
+}
@@ -0,0 +1,103 @@
+use super::*;
+
+fn setup_untracked(ctx: TestContext) -> TestContext {
+    for file in ["file-a", "file-b", "file-c"] {
+        run(&ctx.dir, &["touch", file]);
+    }
+    ctx
+}
+
+fn setup_branch(ctx: TestContext) -> TestContext {
+    run(&ctx.dir, &["git", "checkout", "-b", "other-branch"]);
+    commit(&ctx.dir, "first-file", "first\n");
+    commit(&ctx.dir, "second-file", "second\n");
+    run(&ctx.dir, &["git", "checkout", "main"]);
+    ctx
+}
+
+#[test]
+fn mark_items() {
+    snapshot!(setup_untracked(setup_clone!()), "jj<space>jj<space>");
+}
+
+#[test]
+fn unmark_item() {
+    snapshot!(setup_untracked(setup_clone!()), "jj<space>jj<space><space>");
+}
+
+#[test]
+fn clear_marks() {
+    snapshot!(setup_untracked(setup_clone!()), "jj<space>jj<space>x");
+}
+
+#[test]
+fn stage_marked() {
+    snapshot!(setup_untracked(setup_clone!()), "jj<space>jj<space>s");
+}
+
+#[test]
+fn unstage_marked() {
+    let ctx = setup_untracked(setup_clone!());
+    run(&ctx.dir, &["git", "add", "."]);
+    snapshot!(ctx, "jj<space>jj<space>u");
+}
+
+#[test]
+fn discard_marked() {
+    snapshot!(setup_untracked(setup_clone!()), "jj<space>jj<space>Ky");
+}
+
+#[test]
+fn cherry_pick_marked() {
+    snapshot!(
+        setup_branch(setup_clone!()),
+        "loother-branch<enter><space>j<space>AA"
+    );
+}
+
+#[test]
+fn revert_marked() {
+    let ctx = setup_clone!();
+    commit(&ctx.dir, "first-file", "first\n");
+    commit(&ctx.dir, "second-file", "second\n");
+    snapshot!(ctx, "ll<space>j<space>V-EV");
+}
+
+#[test]
+fn stage_marked_hunk() {
+    let ctx = setup_untracked(setup_clone!());
+    commit(&ctx.dir, "file-d", "a\nb\nc\n");
+    fs::write(ctx.dir.join("file-d"), "x\ny\nz\n").unwrap();
+    let dir = ctx.dir.clone();
+    snapshot!(ctx, "jj<space>jjjj<tab>j<space>s");
+    assert_eq!(
+        run(&dir, &["git", "diff", "--cached", "--name-only"]),
+        "file-a\nfile-d\n"
+    );
+}
+
+#[test]
+fn discard_marked_unsupported() {
+    snapshot!(setup_branch(setup_clone!()), "Yjj<space>K");
+}
+
+#[test]
+fn stage_marked_lines() {
+    let ctx = setup_clone!();
+    commit(&ctx.dir, "file-d", "a\nb\nc\n");
+    fs::write(ctx.dir.join("file-d"), "x\ny\nz\n").unwrap();
+    let dir = ctx.dir.clone();
+    snapshot!(
+        ctx,
+        "jj<tab>j<ctrl+j><space><ctrl+j><ctrl+j><ctrl+j><space>s"
+    );
+    assert_eq!(run(&dir, &["git", "show", ":file-d"]), "b\nc\nx\n");
+}
+
+#[test]
+fn stage_marked_file_and_hunk_unsupported() {
+    let ctx = setup_clone!();
+    commit(&ctx.dir, "file-d", "a\nb\nc\n");
+    fs::write(ctx.dir.join("file-d"), "x\ny\nz\n").unwrap();
+    snapshot!(ctx, "jj<tab><space>j<space>s");
+}
@@ -1,4 +1,4 @@
-use temp_env::with_var;
+use temp_env::{with_var, with_vars};
 
 use super::*;
 
@@ -116,3 +116,155 @@ fn merge_no_ff() {
         snapshot!(setup_branch(setup_clone!()), "m-nmother-branch<enter>");
     });
 }
+
+fn setup_conflict(ctx: TestContext) -> TestContext {
+    commit(&ctx.dir, "doomed-file", "hello\n");
+    commit(&ctx.dir, "new-file", "hello\n");
+
+    run(&ctx.dir, &["git", "checkout", "-b", "other-branch"]);
+    run(&ctx.dir, &["git", "rm", "doomed-file"]);
+    commit(&ctx.dir, "new-file", "theirs\n");
+
+    run(&ctx.dir, &["git", "checkout", "main"]);
+    commit(&ctx.dir, "doomed-file", "ours\n");
+    commit(&ctx.dir, "new-file", "ours\n");
+
+    run_ignore_status(&ctx.dir, &["git", "merge", "other-branch"]);
+    ctx
+}
+
+#[test]
+fn unmerged_paths() {
+    snapshot!(setup_conflict(setup_clone!()), "");
+}
+
+#[test]
+fn unmerged_menu() {
+    snapshot!(setup_conflict(setup_clone!()), "jjjm");
+}
+
+#[test]
+fn unmerged_menu_deleted_by_them() {
+    snapshot!(setup_conflict(setup_clone!()), "jjm");
+}
+
+#[test]
+fn take_ours() {
+    let ctx = setup_conflict(setup_clone!());
+    let dir = ctx.dir.clone();
+    snapshot!(ctx, "jjjmo");
+    assert_eq!(fs::read_to_string(dir.join("new-file")).unwrap(), "ours\n");
+}
+
+#[test]
+fn take_theirs() {
+    let ctx = setup_conflict(setup_clone!());
+    let dir = ctx.dir.clone();
+    snapshot!(ctx, "jjjmt");
+    assert_eq!(
+        fs::read_to_string(dir.join("new-file")).unwrap(),
+        "theirs\n"
+    );
+}
+
+#[test]
+fn mark_resolved() {
+    snapshot!(setup_conflict(setup_clone!()), "jjjmts");
+}
+
+#[test]
+fn mark_resolved_deletion() {
+    let ctx = setup_conflict(setup_clone!());
+    fs::remove_file(ctx.dir.join("doomed-file")).unwrap();
+    snapshot!(ctx, "jjs");
+}
+
+#[test]
+fn conflict_regions() {
+    snapshot!(setup_conflict(setup_clone!()), "jjj<tab>");
+}
+
+#[test]
+fn conflict_region_menu() {
+    snapshot!(setup_conflict(setup_clone!()), "jjj<tab>jm");
+}
+
+#[test]
+fn conflict_region_take_ours() {
+    let ctx = setup_conflict(setup_clone!());
+    let dir = ctx.dir.clone();
+    snapshot!(ctx, "jjj<tab>jmo");
+    assert_eq!(fs::read_to_string(dir.join("new-file")).unwrap(), "ours\n");
+}
+
+#[test]
+fn conflict_region_take_theirs() {
+    let ctx = setup_conflict(setup_clone!());
+    let dir = ctx.dir.clone();
+    snapshot!(ctx, "jjj<tab>jmt");
+    assert_eq!(
+        fs::read_to_string(dir.join("new-file")).unwrap(),
+        "theirs\n"
+    );
+}
+
+#[test]
+fn conflict_region_take_both() {
+    let ctx = setup_conflict(setup_clone!());
+    let dir = ctx.dir.clone();
+    snapshot!(ctx, "jjj<tab>jmb");
+    assert_eq!(
+        fs::read_to_string(dir.join("new-file")).unwrap(),
+        "ours\ntheirs\n"
+    );
+}
+
+#[test]
+fn conflict_region_changed_on_disk() {
+    let mut ctx = setup_conflict(setup_clone!());
+    let mut app = ctx.init_app();
+    ctx.update(&mut app, keys("jjj<tab>j"));
+
+    let path = ctx.dir.join("new-file");
+    let edited = fs::read_to_string(&path).unwrap().replace("ours", "edited");
+    fs::write(&path, &edited).unwrap();
+    ctx.update(&mut app, keys("mo"));
+
+    insta::assert_snapshot!(ctx.redact_buffer());
+    assert_eq!(fs::read_to_string(&path).unwrap(), edited);
+}
+
+#[test]
+fn conflict_region_edit() {
+    let ctx = setup_conflict(setup_clone!());
+    let dir = ctx.dir.clone();
+
+    // Named like vi so the line is passed as `+<line>`
+    let editor = dir.join(".git/fake-vi");
+    fs::write(
+        &editor,
+        "#!/bin/sh\nsed -i \"s/ours/edited at $1/\" \"$2\"\n",
+    )
+    .unwrap();
+    run(&dir, &["chmod", "+x", editor.to_str().unwrap()]);
+
+    let path = format!(
+        "{}:{}",
+        dir.join(".git").to_str().unwrap(),
+        std::env::var("PATH").unwrap()
+    );
+    with_vars(
+        [
+            ("GITU_SHOW_EDITOR", Some("fake-vi")),
+            ("PATH", Some(path.as_str())),
+        ],
+        || {
+            snapshot!(ctx, "jjj<tab>j<enter>");
+        },
+    );
+    assert!(
+        fs::read_to_string(dir.join("new-file"))
+            .unwrap()
+            .contains("edited at +1\n")
+    );
+}
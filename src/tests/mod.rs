@@ -14,24 +14,36 @@ use std::fs;
 #[macro_use]
 mod helpers;
 mod arg;
+mod bisect;
+mod blame;
 mod branch;
+mod branch_config;
 mod cherry_pick;
+mod clean;
 mod commit;
+mod diff;
 mod discard;
 mod editor;
 mod fetch;
+mod ignore;
 mod log;
+mod mark;
 mod merge;
+mod patch;
 mod pull;
 mod push;
 mod quit;
 mod rebase;
+mod reflog;
 mod remote;
 mod reset;
 mod reverse;
 mod stage;
 mod stash;
+mod submodule;
+mod tag;
 mod unstage;
+mod worktree;
 
 use crossterm::event::MouseButton;
 use helpers::{TestContext, clone_and_commit, commit, keys, mouse_event, mouse_scroll_event, run};
@@ -526,6 +538,24 @@ fn mouse_select_hunk_line() {
     insta::assert_snapshot!(ctx.redact_buffer());
 }
 
+#[test]
+fn mouse_show_commit() {
+    let mut ctx = setup_clone!();
+    ctx.config().general.mouse_support = true;
+
+    let mut app = ctx.init_app();
+    ctx.update(
+        &mut app,
+        vec![
+            // Select the recent commit.
+            mouse_event(0, 5, MouseButton::Left),
+            // Show it, as a commit in the log isn't collapsible.
+            mouse_event(0, 5, MouseButton::Left),
+        ],
+    );
+    insta::assert_snapshot!(ctx.redact_buffer());
+}
+
 #[test]
 fn mouse_select_ignore_empty_lines() {
     let mut ctx = setup_clone!();
@@ -0,0 +1,114 @@
+use super::*;
+
+fn setup_patches(ctx: TestContext) -> TestContext {
+    commit(&ctx.dir, "first-file", "first\n");
+    commit(&ctx.dir, "second-file", "second\n");
+    run(
+        &ctx.dir,
+        &["git", "format-patch", "-o", "patches", "HEAD~2"],
+    );
+    run(&ctx.dir, &["git", "reset", "--hard", "HEAD~2"]);
+    ctx
+}
+
+fn setup_am_conflict(ctx: TestContext) -> TestContext {
+    commit(&ctx.dir, "conflict-file", "hello\n");
+    commit(&ctx.dir, "conflict-file", "hey\n");
+    run(
+        &ctx.dir,
+        &["git", "format-patch", "-o", "patches", "HEAD~1"],
+    );
+    run(&ctx.dir, &["git", "reset", "--hard", "HEAD~1"]);
+    commit(&ctx.dir, "conflict-file", "hi\n");
+    run_ignore_status(
+        &ctx.dir,
+        &["git", "am", "patches/0001-modify-conflict-file.patch"],
+    );
+    ctx
+}
+
+#[test]
+fn patch_menu() {
+    snapshot!(setup_clone!(), "W");
+}
+
+#[test]
+fn format_patch_prompt() {
+    let ctx = setup_clone!();
+    commit(&ctx.dir, "first-file", "first\n");
+    snapshot!(ctx, "Wf");
+}
+
+#[test]
+fn format_patch() {
+    let ctx = setup_clone!();
+    commit(&ctx.dir, "first-file", "first\n");
+    commit(&ctx.dir, "second-file", "second\n");
+    let dir = ctx.dir.clone();
+    snapshot!(ctx, "W-c-opatches<enter>-v2<enter>f<enter>");
+
+    let mut files = fs::read_dir(dir.join("patches"))
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .collect::<Vec<_>>();
+    files.sort();
+    assert_eq!(
+        files,
+        [
+            "v2-0000-cover-letter.patch",
+            "v2-0001-add-first-file.patch",
+            "v2-0002-add-second-file.patch",
+        ]
+    );
+}
+
+#[test]
+fn format_patch_from_selected_commit() {
+    let ctx = setup_clone!();
+    commit(&ctx.dir, "first-file", "first\n");
+    commit(&ctx.dir, "second-file", "second\n");
+    let dir = ctx.dir.clone();
+    // The range ends at the commit under the cursor, not at HEAD
+    snapshot!(ctx, "lljWf<enter>");
+
+    assert!(dir.join("0001-add-first-file.patch").exists());
+    assert!(!dir.join("0002-add-second-file.patch").exists());
+}
+
+#[test]
+fn am() {
+    snapshot!(setup_patches(setup_clone!()), "W-swpatches<enter>");
+}
+
+#[test]
+fn am_mbox() {
+    snapshot!(
+        setup_patches(setup_clone!()),
+        "Wwpatches/0001-add-first-file.patch<enter>"
+    );
+}
+
+#[test]
+fn am_conflict_status() {
+    let mut ctx = setup_am_conflict(setup_clone!());
+    ctx.init_app();
+    insta::assert_snapshot!(ctx.redact_buffer());
+}
+
+#[test]
+fn am_abort() {
+    snapshot!(setup_am_conflict(setup_clone!()), "Wa");
+}
+
+#[test]
+fn am_skip() {
+    snapshot!(setup_am_conflict(setup_clone!()), "Ws");
+}
+
+#[test]
+fn am_continue() {
+    let ctx = setup_am_conflict(setup_clone!());
+    fs::write(ctx.dir.join("conflict-file"), "hey\n").unwrap();
+    run(&ctx.dir, &["git", "add", "conflict-file"]);
+    snapshot!(ctx, "Wc");
+}
@@ -1,4 +1,5 @@
 use super::*;
+use temp_env::with_var;
 
 fn setup(ctx: TestContext) -> TestContext {
     run(&ctx.dir, &["git", "checkout", "-b", "other-branch"]);
@@ -22,3 +23,274 @@ fn rebase_elsewhere_prompt() {
 fn rebase_elsewhere() {
     snapshot!(setup(setup_clone!()), "remain<enter>");
 }
+
+fn setup_todo(ctx: TestContext) -> TestContext {
+    commit(&ctx.dir, "file-a", "");
+    commit(&ctx.dir, "file-b", "");
+    commit(&ctx.dir, "file-c", "");
+    ctx
+}
+
+#[test]
+fn rebase_interactive_todo() {
+    snapshot!(setup_todo(setup_clone!()), "lljjri");
+}
+
+#[test]
+fn rebase_todo_set_actions() {
+    snapshot!(setup_todo(setup_clone!()), "lljjrirjsjf");
+}
+
+#[test]
+fn rebase_todo_move_down() {
+    snapshot!(setup_todo(setup_clone!()), "lljjri<alt+j>");
+}
+
+#[test]
+fn rebase_todo_move_up() {
+    snapshot!(setup_todo(setup_clone!()), "lljjrijj<alt+k>");
+}
+
+#[test]
+fn rebase_todo_exec() {
+    snapshot!(setup_todo(setup_clone!()), "lljjrixtrue<enter>");
+}
+
+#[test]
+fn rebase_todo_drop_exec() {
+    snapshot!(setup_todo(setup_clone!()), "lljjrixtrue<enter>d");
+}
+
+#[test]
+fn rebase_todo_show_commit() {
+    snapshot!(setup_todo(setup_clone!()), "lljjrij<enter>");
+}
+
+#[test]
+fn rebase_todo_confirm() {
+    snapshot!(
+        setup_todo(setup_clone!()),
+        "lljjri<alt+j>jd<ctrl+c><ctrl+c>"
+    );
+}
+
+#[test]
+fn rebase_todo_abort() {
+    snapshot!(setup_todo(setup_clone!()), "lljjri<alt+j>q");
+}
+
+#[test]
+fn rebase_status_lists_todo_and_done() {
+    snapshot!(setup_todo(setup_clone!()), "lljjrije<ctrl+c><ctrl+c>q");
+}
+
+#[test]
+fn rebase_status_show_todo_commit() {
+    snapshot!(
+        setup_todo(setup_clone!()),
+        "lljjrije<ctrl+c><ctrl+c>qj<enter>"
+    );
+}
+
+#[test]
+fn rebase_menu_on_commit() {
+    snapshot!(setup_todo(setup_clone!()), "lljr");
+}
+
+#[test]
+fn reword_commit() {
+    with_var("GIT_EDITOR", Some("echo 'reworded message' >"), || {
+        snapshot!(setup_todo(setup_clone!()), "lljrw");
+    });
+}
+
+#[test]
+fn reword_root_commit() {
+    with_var("GIT_EDITOR", Some("echo 'reworded message' >"), || {
+        snapshot!(setup_todo(setup_clone!()), "lljjjrw");
+    });
+}
+
+#[test]
+fn edit_commit() {
+    let ctx = setup_todo(setup_clone!());
+    let dir = ctx.dir.clone();
+    snapshot!(ctx, "lljrmq");
+    assert!(dir.join(".git/rebase-merge").exists());
+}
+
+#[test]
+fn drop_commit() {
+    snapshot!(setup_todo(setup_clone!()), "lljrky");
+}
+
+#[test]
+fn drop_commit_abbreviated_commands() {
+    let ctx = setup_todo(setup_clone!());
+    run(
+        &ctx.dir,
+        &["git", "config", "rebase.abbreviateCommands", "true"],
+    );
+    let dir = ctx.dir.clone();
+    snapshot!(ctx, "lljrky");
+    assert_eq!(
+        run(&dir, &["git", "log", "--format=%s", "-2"]),
+        "add file-c\nadd file-a\n"
+    );
+}
+
+#[test]
+fn drop_commit_abort() {
+    snapshot!(setup_todo(setup_clone!()), "lljrkn");
+}
+
+#[test]
+fn drop_commit_conflict() {
+    let ctx = setup_clone!();
+    commit(&ctx.dir, "file-a", "one");
+    commit(&ctx.dir, "file-a", "two");
+    snapshot!(ctx, "lljrky");
+}
+
+#[test]
+fn drop_commit_not_on_branch() {
+    let ctx = setup_todo(setup_clone!());
+    run(&ctx.dir, &["git", "checkout", "-b", "other-branch"]);
+    commit(&ctx.dir, "other-file", "");
+    run(&ctx.dir, &["git", "checkout", "main"]);
+    let dir = ctx.dir.clone();
+    snapshot!(ctx, "loother-branch<enter>rk");
+    assert_eq!(
+        run(&dir, &["git", "log", "--format=%s", "-1"]),
+        "add file-c\n"
+    );
+}
+
+fn setup_merge(ctx: TestContext) -> TestContext {
+    run(&ctx.dir, &["git", "checkout", "-b", "other-branch"]);
+    commit(&ctx.dir, "file-a", "");
+    run(&ctx.dir, &["git", "checkout", "main"]);
+    commit(&ctx.dir, "file-b", "");
+    run(&ctx.dir, &["git", "merge", "--no-edit", "other-branch"]);
+    commit(&ctx.dir, "file-c", "");
+    ctx
+}
+
+#[test]
+fn reword_commit_across_merge() {
+    snapshot!(setup_merge(setup_clone!()), "lljjrw");
+}
+
+#[test]
+fn move_commit_up() {
+    snapshot!(setup_todo(setup_clone!()), "lljr<alt+k>q");
+}
+
+#[test]
+fn move_commit_down() {
+    snapshot!(setup_todo(setup_clone!()), "lljr<alt+j>q");
+}
+
+#[test]
+fn move_commit_up_twice() {
+    snapshot!(setup_todo(setup_clone!()), "lljjr<alt+k><alt+k>q");
+}
+
+#[test]
+fn move_commit_in_recent_commits() {
+    snapshot!(setup_todo(setup_clone!()), "jjr<alt+j>q");
+}
+
+#[test]
+fn move_newest_commit_up() {
+    snapshot!(setup_todo(setup_clone!()), "llr<alt+k>");
+}
+
+#[test]
+fn move_pushed_commit() {
+    snapshot!(setup_todo(setup_clone!()), "lljjr<alt+j>");
+}
+
+#[test]
+fn move_commit_across_merge() {
+    snapshot!(setup_merge(setup_clone!()), "llr<alt+j>");
+}
+
+fn setup_split(ctx: TestContext) -> TestContext {
+    fs::write(ctx.dir.join("file-a"), "a\n").unwrap();
+    fs::write(ctx.dir.join("file-b"), "b\n").unwrap();
+    run(&ctx.dir, &["git", "add", "."]);
+    run(&ctx.dir, &["git", "commit", "-m", "add file-a and file-b"]);
+    commit(&ctx.dir, "file-c", "");
+    ctx
+}
+
+#[test]
+fn split_commit() {
+    snapshot!(setup_split(setup_clone!()), "lljrsq");
+}
+
+#[test]
+fn split_root_commit() {
+    snapshot!(setup_split(setup_clone!()), "lljjrs");
+}
+
+#[test]
+fn split_commit_counts_created_commits() {
+    let mut ctx = setup_split(setup_clone!());
+    let mut app = ctx.init_app();
+    ctx.update(&mut app, keys("lljrsq"));
+
+    run(&ctx.dir, &["git", "add", "file-a"]);
+    run(&ctx.dir, &["git", "commit", "-m", "add file-a"]);
+    ctx.update(&mut app, keys("g"));
+
+    insta::assert_snapshot!(ctx.redact_buffer());
+}
+
+#[test]
+fn split_commit_continues_when_clean() {
+    let mut ctx = setup_split(setup_clone!());
+    let mut app = ctx.init_app();
+    ctx.update(&mut app, keys("lljrsq"));
+
+    run(&ctx.dir, &["git", "add", "file-a"]);
+    run(&ctx.dir, &["git", "commit", "-m", "add file-a"]);
+    run(&ctx.dir, &["git", "add", "file-b"]);
+    with_var("GIT_EDITOR", Some("echo 'add file-b' >"), || {
+        ctx.update(&mut app, keys("cc"));
+    });
+
+    insta::assert_snapshot!(ctx.redact_buffer());
+    assert!(!ctx.dir.join(".git/rebase-merge").exists());
+    assert_eq!(
+        run(&ctx.dir, &["git", "log", "--format=%s"]),
+        "add file-c\nadd file-b\nadd file-a\nadd initial-file\n"
+    );
+}
+
+#[test]
+fn split_commit_continues_on_refresh_with_untracked_files() {
+    let mut ctx = setup_split(setup_clone!());
+    run(&ctx.dir, &["touch", "unrelated-file"]);
+    let mut app = ctx.init_app();
+    ctx.update(&mut app, keys("lljrsq"));
+
+    run(&ctx.dir, &["git", "add", "file-a"]);
+    run(&ctx.dir, &["git", "commit", "-m", "add file-a"]);
+    run(&ctx.dir, &["git", "add", "file-b"]);
+    run(&ctx.dir, &["git", "commit", "-m", "add file-b"]);
+    ctx.update(&mut app, keys("g"));
+
+    insta::assert_snapshot!(ctx.redact_buffer());
+    assert!(!ctx.dir.join(".git/rebase-merge").exists());
+}
+
+#[test]
+fn split_commit_not_on_branch() {
+    let ctx = setup_split(setup_clone!());
+    run(&ctx.dir, &["git", "checkout", "-b", "other-branch"]);
+    commit(&ctx.dir, "other-file", "");
+    run(&ctx.dir, &["git", "checkout", "main"]);
+    snapshot!(ctx, "loother-branch<enter>rs");
+}
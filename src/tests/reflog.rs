@@ -0,0 +1,43 @@
+use super::*;
+
+fn setup(ctx: TestContext) -> TestContext {
+    run(
+        &ctx.dir,
+        &["git", "reflog", "expire", "--expire=all", "--all"],
+    );
+    commit(&ctx.dir, "file-a", "");
+    commit(&ctx.dir, "file-b", "");
+    run(&ctx.dir, &["git", "reset", "--hard", "HEAD~1"]);
+    run(&ctx.dir, &["git", "branch", "other-branch"]);
+    ctx
+}
+
+#[test]
+fn reflog_head() {
+    snapshot!(setup(setup_clone!()), "lr");
+}
+
+#[test]
+fn reflog_branch_from_refs() {
+    snapshot!(setup(setup_clone!()), "Yjjlr");
+}
+
+#[test]
+fn reflog_show_entry() {
+    snapshot!(setup(setup_clone!()), "lrj<enter>");
+}
+
+#[test]
+fn reflog_reset_hard() {
+    snapshot!(setup(setup_clone!()), "lrjXh<enter>q");
+}
+
+#[test]
+fn reflog_checkout() {
+    snapshot!(setup(setup_clone!()), "lrjjbb<enter>q");
+}
+
+#[test]
+fn reflog_cherry_pick() {
+    snapshot!(setup(setup_clone!()), "lrjAA<enter>q");
+}
@@ -1,4 +1,5 @@
 use super::*;
+use temp_env::with_var;
 
 #[test]
 fn staged_file() {
@@ -74,3 +75,34 @@ fn stage_deleted_executable_file() {
     run(&ctx.dir, &["rm", "script.sh"]);
     snapshot!(ctx, "jjs");
 }
+
+#[test]
+fn stage_edited_hunk() {
+    let ctx = setup_clone!();
+    commit(&ctx.dir, "firstfile", "testing\ntesttest\n");
+    fs::write(ctx.dir.join("firstfile"), "weehooo\nblrergh\n").unwrap();
+
+    let dir = ctx.dir.clone();
+    with_var(
+        "GITU_SHOW_EDITOR",
+        Some("sed -i s/^+weehooo/+edited/"),
+        || {
+            snapshot!(ctx, "jj<tab>jE");
+        },
+    );
+
+    let staged = run(&dir, &["git", "diff", "--cached"]);
+    assert!(staged.contains("+edited\n"));
+    assert!(!staged.contains("weehooo"));
+}
+
+#[test]
+fn stage_edited_hunk_invalid() {
+    let ctx = setup_clone!();
+    commit(&ctx.dir, "firstfile", "testing\ntesttest\n");
+    fs::write(ctx.dir.join("firstfile"), "weehooo\nblrergh\n").unwrap();
+
+    with_var("GITU_SHOW_EDITOR", Some("sed -i s/^@@/xx/"), || {
+        snapshot!(ctx, "jj<tab>jE");
+    });
+}
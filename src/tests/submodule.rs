@@ -0,0 +1,139 @@
+use super::*;
+
+fn setup(ctx: &TestContext) -> std::path::PathBuf {
+    run(
+        &ctx.dir,
+        &[
+            "git",
+            "-c",
+            "protocol.file.allow=always",
+            "submodule",
+            "add",
+            "../remote",
+            "test-submodule",
+        ],
+    );
+    run(&ctx.dir, &["git", "commit", "-m", "add test-submodule"]);
+
+    let submodule_dir = ctx.dir.join("test-submodule");
+    run(
+        &submodule_dir,
+        &["git", "config", "user.name", "Author Name"],
+    );
+    run(
+        &submodule_dir,
+        &["git", "config", "user.email", "author@email.com"],
+    );
+    submodule_dir
+}
+
+/// Git refuses to clone or fetch submodules over `file://` by default
+fn with_file_protocol_allowed(f: impl FnOnce()) {
+    temp_env::with_vars(
+        [
+            ("GIT_CONFIG_COUNT", Some("1")),
+            ("GIT_CONFIG_KEY_0", Some("protocol.file.allow")),
+            ("GIT_CONFIG_VALUE_0", Some("always")),
+        ],
+        f,
+    );
+}
+
+#[test]
+fn submodule_menu() {
+    let ctx = setup_clone!();
+    setup(&ctx);
+    snapshot!(ctx, "o");
+}
+
+#[test]
+fn submodule_section() {
+    let mut ctx = setup_clone!();
+    setup(&ctx);
+
+    ctx.init_app();
+    insta::assert_snapshot!(ctx.redact_buffer());
+}
+
+#[test]
+fn submodule_new_commits() {
+    let mut ctx = setup_clone!();
+    let submodule_dir = setup(&ctx);
+    commit(&submodule_dir, "new-file", "hello");
+
+    ctx.init_app();
+    insta::assert_snapshot!(ctx.redact_buffer());
+}
+
+#[test]
+fn submodule_dirty() {
+    let mut ctx = setup_clone!();
+    let submodule_dir = setup(&ctx);
+    fs::write(submodule_dir.join("initial-file"), "modified").unwrap();
+
+    ctx.init_app();
+    insta::assert_snapshot!(ctx.redact_buffer());
+}
+
+#[test]
+fn submodule_uninitialized() {
+    let mut ctx = setup_clone!();
+    setup(&ctx);
+    run(&ctx.dir, &["git", "submodule", "deinit", "--all"]);
+
+    ctx.init_app();
+    insta::assert_snapshot!(ctx.redact_buffer());
+}
+
+#[test]
+fn show_submodule_log() {
+    let ctx = setup_clone!();
+    let submodule_dir = setup(&ctx);
+    commit(&submodule_dir, "new-file", "hello");
+    commit(&submodule_dir, "new-file-2", "hello");
+    snapshot!(ctx, "jjjj<enter>");
+}
+
+#[test]
+fn show_submodule_delta() {
+    let ctx = setup_clone!();
+    let submodule_dir = setup(&ctx);
+    commit(&submodule_dir, "new-file", "hello");
+    snapshot!(ctx, "jj<enter>");
+}
+
+#[test]
+fn init_and_update_submodules() {
+    let ctx = setup_clone!();
+    setup(&ctx);
+    run(&ctx.dir, &["git", "submodule", "deinit", "--all"]);
+
+    with_file_protocol_allowed(|| {
+        snapshot!(ctx, "oiou");
+    });
+}
+
+#[test]
+fn update_submodule() {
+    let ctx = setup_clone!();
+    let submodule_dir = setup(&ctx);
+    commit(&submodule_dir, "new-file", "hello");
+    snapshot!(ctx, "ou");
+}
+
+#[test]
+fn sync_submodules() {
+    let ctx = setup_clone!();
+    setup(&ctx);
+    snapshot!(ctx, "o-rs");
+}
+
+#[test]
+fn fetch_submodules() {
+    let ctx = setup_clone!();
+    setup(&ctx);
+
+    with_file_protocol_allowed(|| {
+        snapshot!(ctx, "of");
+    });
+}
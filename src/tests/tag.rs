@@ -0,0 +1,74 @@
+use super::*;
+
+fn setup(ctx: &TestContext) {
+    commit(&ctx.dir, "first-file", "");
+    commit(&ctx.dir, "second-file", "");
+    run(&ctx.dir, &["git", "tag", "v0.1.0", "HEAD~1"]);
+}
+
+#[test]
+fn tag_menu() {
+    let ctx = setup_clone!();
+    setup(&ctx);
+    snapshot!(ctx, "t");
+}
+
+#[test]
+fn create_tag() {
+    let ctx = setup_clone!();
+    setup(&ctx);
+    snapshot!(ctx, "ttv1.0.0<enter><enter>");
+}
+
+#[test]
+fn create_tag_empty_name() {
+    let ctx = setup_clone!();
+    setup(&ctx);
+    snapshot!(ctx, "tt<enter>");
+}
+
+#[test]
+fn create_tag_on_selected_commit() {
+    let ctx = setup_clone!();
+    setup(&ctx);
+    snapshot!(ctx, "lljttv0.0.1<enter><enter>");
+}
+
+#[test]
+fn create_annotated_tag() {
+    let ctx = setup_clone!();
+    setup(&ctx);
+
+    temp_env::with_var("GIT_EDITOR", Some("echo 'Release' >"), || {
+        snapshot!(ctx, "tav1.0.0<enter><enter>ll");
+    });
+}
+
+#[test]
+fn delete_tag() {
+    let ctx = setup_clone!();
+    setup(&ctx);
+    snapshot!(ctx, "tK<enter>");
+}
+
+#[test]
+fn push_tag() {
+    let ctx = setup_clone!();
+    setup(&ctx);
+    snapshot!(ctx, "tp<enter><enter>");
+}
+
+#[test]
+fn push_all_tags() {
+    let ctx = setup_clone!();
+    setup(&ctx);
+    snapshot!(ctx, "tP<enter>");
+}
+
+#[test]
+fn delete_remote_tag() {
+    let ctx = setup_clone!();
+    setup(&ctx);
+    run(&ctx.dir, &["git", "push", "origin", "v0.1.0"]);
+    snapshot!(ctx, "td<enter><enter>y");
+}
@@ -0,0 +1,78 @@
+use super::*;
+
+fn setup(ctx: &TestContext) {
+    run(
+        &ctx.dir,
+        &["git", "worktree", "add", "-b", "other-branch", "../other"],
+    );
+}
+
+#[test]
+fn worktree_menu() {
+    let ctx = setup_clone!();
+    snapshot!(ctx, "%");
+}
+
+#[test]
+fn worktree_section() {
+    let mut ctx = setup_clone!();
+    setup(&ctx);
+
+    ctx.init_app();
+    insta::assert_snapshot!(ctx.redact_buffer());
+}
+
+#[test]
+fn add_worktree() {
+    let ctx = setup_clone!();
+    run(&ctx.dir, &["git", "branch", "feature"]);
+    snapshot!(ctx, "%cfeature<enter><enter>");
+}
+
+#[test]
+fn switch_worktree() {
+    let ctx = setup_clone!();
+    setup(&ctx);
+    snapshot!(ctx, "%g<enter>");
+}
+
+#[test]
+fn switch_worktree_no_other_worktrees() {
+    let ctx = setup_clone!();
+    snapshot!(ctx, "%g");
+}
+
+#[test]
+fn show_worktree_switches() {
+    let ctx = setup_clone!();
+    setup(&ctx);
+    snapshot!(ctx, "jj<enter>");
+}
+
+#[test]
+fn remove_worktree() {
+    let ctx = setup_clone!();
+    setup(&ctx);
+    snapshot!(ctx, "%k<enter>y");
+}
+
+#[test]
+fn prune_worktrees() {
+    let ctx = setup_clone!();
+    setup(&ctx);
+    fs::remove_dir_all(ctx.dir.join("../other")).unwrap();
+    snapshot!(ctx, "%p");
+}
+
+#[test]
+fn rebase_conflict_in_worktree() {
+    let mut ctx = setup_clone!();
+    setup(&ctx);
+    let other = ctx.dir.join("../other");
+    commit(&ctx.dir, "new-file", "hi");
+    commit(&other, "new-file", "hey");
+    run_ignore_status(&other, &["git", "rebase", "main"]);
+
+    ctx.init_app_at_path(other);
+    insta::assert_snapshot!(ctx.redact_buffer());
+}
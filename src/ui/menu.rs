@@ -2,7 +2,11 @@ use std::sync::Arc;
 
 use crate::ui::layout::OPTS;
 use crate::ui::{self, UiTree};
-use crate::{app::State, ops::Op, ui::layout_line};
+use crate::{
+    app::State,
+    ops::{self, Op},
+    ui::layout_line,
+};
 use itertools::Itertools;
 use ratatui::{
     style::Style,
@@ -23,7 +27,8 @@ pub(crate) fn layout_menu<'a>(layout: &mut UiTree<'a>, state: &'a State, width:
     }
 
     let config = Arc::clone(&state.config);
-    let item = state.screens.last().unwrap().get_selected_item();
+    let screen = state.screens.last().unwrap();
+    let item = screen.get_selected_item();
     let style = &config.style;
 
     let arg_binds = config.bindings.arg_list(pending).collect::<Vec<_>>();
@@ -34,12 +39,12 @@ pub(crate) fn layout_menu<'a>(layout: &mut UiTree<'a>, state: &'a State, width:
     let target_binds: Vec<_> = target_binds
         .into_iter()
         .filter(|keybind| {
-            keybind
-                .op
-                .clone()
-                .implementation()
-                .get_action(&item.data)
-                .is_some()
+            let implementation = keybind.op.clone().implementation();
+            match ops::marked_action(implementation.as_ref(), screen) {
+                Ok(Some(_)) => true,
+                Ok(None) => implementation.get_action(&item.data).is_some(),
+                Err(_) => false,
+            }
         })
         .collect();
     let (menu_binds, non_menu_binds): (Vec<_>, Vec<_>) = non_target_binds